@@ -1,6 +1,10 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::cmp::Ordering;
+use core::fmt;
 
 /// Normalized risk coordinates for one hive window.
 /// All components must be in [0, 1], where 0 = no added load, 1 = corridor edge.
@@ -29,6 +33,111 @@ pub struct BeeRiskWeights {
     pub r_hard: f64,
 }
 
+/// Allowed deviation of the weight sum from 1.
+///
+/// With every r_x in [0, 1], V_bee never exceeds the weight sum, so v_safe
+/// and v_crit are only meaningful against normalized weights.
+pub const WEIGHT_SUM_TOLERANCE: f64 = 0.01;
+
+/// Why a [`BeeRiskWeights`] is rejected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WeightError {
+    NonFinite { field: &'static str },
+    NegativeWeight { field: &'static str, value: f64 },
+    WeightSum { sum: f64 },
+    /// v_safe must be positive and not above v_crit; v_crit below the
+    /// weight sum; r_hard in (0, 1].
+    Threshold { field: &'static str, value: f64 },
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::NonFinite { field } => write!(f, "`{field}` is not finite"),
+            WeightError::NegativeWeight { field, value } => {
+                write!(f, "`{field}` is negative ({value})")
+            }
+            WeightError::WeightSum { sum } => write!(
+                f,
+                "weights sum to {sum}, expected 1 ± {WEIGHT_SUM_TOLERANCE}"
+            ),
+            WeightError::Threshold { field, value } => {
+                write!(f, "`{field}` = {value} is out of range")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightError {}
+
+impl BeeRiskWeights {
+    /// Check the weights and thresholds, returning them unchanged if sane.
+    pub fn validated(self) -> Result<Self, WeightError> {
+        self.validate().map(|()| self)
+    }
+
+    /// Weights finite, non-negative and summing to 1 (within
+    /// [`WEIGHT_SUM_TOLERANCE`]); 0 < v_safe <= v_crit < sum; 0 < r_hard <= 1.
+    pub fn validate(&self) -> Result<(), WeightError> {
+        let weights = self.weights();
+        let fields = [
+            ("w_thermal", weights[0]),
+            ("w_parasite", weights[1]),
+            ("w_nutrition", weights[2]),
+            ("w_disturb", weights[3]),
+            ("w_sigma", weights[4]),
+            ("v_safe", self.v_safe),
+            ("v_crit", self.v_crit),
+            ("r_hard", self.r_hard),
+        ];
+        for (field, value) in fields {
+            if !value.is_finite() {
+                return Err(WeightError::NonFinite { field });
+            }
+        }
+        for &(field, value) in &fields[..5] {
+            if value < 0.0 {
+                return Err(WeightError::NegativeWeight { field, value });
+            }
+        }
+        let sum: f64 = weights.iter().sum();
+        if (sum - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+            return Err(WeightError::WeightSum { sum });
+        }
+        if self.v_safe <= 0.0 || self.v_safe > self.v_crit {
+            return Err(WeightError::Threshold {
+                field: "v_safe",
+                value: self.v_safe,
+            });
+        }
+        if self.v_crit >= sum {
+            return Err(WeightError::Threshold {
+                field: "v_crit",
+                value: self.v_crit,
+            });
+        }
+        if self.r_hard <= 0.0 || self.r_hard > 1.0 {
+            return Err(WeightError::Threshold {
+                field: "r_hard",
+                value: self.r_hard,
+            });
+        }
+        Ok(())
+    }
+
+    /// Weights in coordinate order: thermal, parasite, nutrition, disturb, sigma.
+    pub fn weights(&self) -> [f64; 5] {
+        [
+            self.w_thermal,
+            self.w_parasite,
+            self.w_nutrition,
+            self.w_disturb,
+            self.w_sigma,
+        ]
+    }
+}
+
 /// Per-window summary of risk and BeeNeuralSafe status.
 #[derive(Copy, Clone, Debug)]
 pub struct BeeRiskSummary {
@@ -112,6 +221,904 @@ pub fn permit_actions(
     curr.bee_neural_safe && lyapunov_non_increase(prev, curr, w)
 }
 
+/// Graded BeeRoH state of a hive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BeeRohState {
+    /// V_bee <= v_safe and every coordinate within r_hard.
+    Safe,
+    /// Above v_safe, not above v_crit, or V_bee / max_r unknown (non-finite).
+    Watch,
+    /// Above v_crit: in the irreversible-harm band.
+    Critical,
+    /// Hard coordinate breach or persistent Critical; cleared only by a run
+    /// of safe windows.
+    Lockout,
+}
+
+/// Hysteresis and dwell settings for [`BeeRohMonitor`].
+#[derive(Copy, Clone, Debug)]
+pub struct BeeRohHysteresis {
+    /// Relative margin below a ceiling required to leave a state, e.g. 0.1
+    /// leaves Watch only once V_bee <= 0.9 * v_safe. Escalation uses the
+    /// ceilings themselves.
+    pub band: f64,
+    /// Windows a state must be held before de-escalating.
+    pub min_dwell_windows: u32,
+    /// Consecutive Critical windows that escalate to Lockout.
+    pub lockout_after_critical_windows: u32,
+    /// Consecutive safe windows (with margin) that clear Lockout; at least 1.
+    pub lockout_clear_windows: u32,
+}
+
+impl Default for BeeRohHysteresis {
+    fn default() -> Self {
+        BeeRohHysteresis {
+            band: 0.1,
+            min_dwell_windows: 3,
+            lockout_after_critical_windows: 3,
+            lockout_clear_windows: 6,
+        }
+    }
+}
+
+/// Window-by-window BeeRoH state machine.
+///
+/// Escalation is immediate; de-escalation needs both the hysteresis margin
+/// and the minimum dwell, so a hive oscillating around v_safe stays in
+/// Watch instead of flapping actuation permission every window.
+#[derive(Copy, Clone, Debug)]
+pub struct BeeRohMonitor {
+    config: BeeRohHysteresis,
+    state: BeeRohState,
+    dwell: u32,
+    critical_streak: u32,
+    safe_streak: u32,
+    prev: Option<BeeRiskSummary>,
+    curr: Option<BeeRiskSummary>,
+}
+
+impl BeeRohMonitor {
+    /// Start in Watch: a hive is not Safe until it has shown it.
+    pub fn new(config: BeeRohHysteresis) -> Self {
+        BeeRohMonitor {
+            config,
+            state: BeeRohState::Watch,
+            dwell: 0,
+            critical_streak: 0,
+            safe_streak: 0,
+            prev: None,
+            curr: None,
+        }
+    }
+
+    pub fn state(&self) -> BeeRohState {
+        self.state
+    }
+
+    /// Advance by one window and return the new state.
+    pub fn update(&mut self, curr: &BeeRiskSummary, w: &BeeRiskWeights) -> BeeRohState {
+        let keep = 1.0 - self.config.band;
+        // A window that cannot be scored is never Safe.
+        let unknown = !(curr.v_bee.is_finite() && curr.max_r.is_finite());
+        let target = if curr.v_bee > w.v_crit {
+            BeeRohState::Critical
+        } else if unknown || curr.v_bee > w.v_safe {
+            BeeRohState::Watch
+        } else {
+            BeeRohState::Safe
+        };
+        // Safe with margin below every ceiling.
+        let clearly_safe = curr.v_bee <= keep * w.v_safe && curr.max_r <= keep * w.r_hard;
+
+        self.critical_streak = if target == BeeRohState::Critical {
+            self.critical_streak + 1
+        } else {
+            0
+        };
+        self.safe_streak = if clearly_safe { self.safe_streak + 1 } else { 0 };
+        let dwelt = self.dwell + 1 >= self.config.min_dwell_windows;
+
+        let next = if curr.max_r > w.r_hard
+            || self.critical_streak >= self.config.lockout_after_critical_windows.max(1)
+        {
+            BeeRohState::Lockout
+        } else if self.state == BeeRohState::Lockout {
+            if clearly_safe && self.safe_streak >= self.config.lockout_clear_windows.max(1) {
+                BeeRohState::Safe
+            } else {
+                BeeRohState::Lockout
+            }
+        } else if target.rank() >= self.state.rank() {
+            target
+        } else if !dwelt {
+            self.state
+        } else if clearly_safe {
+            BeeRohState::Safe
+        } else if self.state == BeeRohState::Critical && curr.v_bee <= keep * w.v_crit {
+            BeeRohState::Watch
+        } else {
+            self.state
+        };
+
+        if next == self.state {
+            self.dwell = self.dwell.saturating_add(1);
+        } else {
+            self.state = next;
+            self.dwell = 0;
+        }
+        self.prev = self.curr.replace(*curr);
+        next
+    }
+
+    /// Graded form of [`permit_actions`]: the monitor must be Safe, the
+    /// latest window BeeNeuralSafe, and the last step Lyapunov non-increasing.
+    pub fn permits_actions(&self, w: &BeeRiskWeights) -> bool {
+        let Some(curr) = self.curr else {
+            return false;
+        };
+        self.state == BeeRohState::Safe
+            && match self.prev {
+                Some(prev) => permit_actions(&prev, &curr, w),
+                None => curr.bee_neural_safe,
+            }
+    }
+}
+
+impl BeeRohState {
+    fn rank(self) -> u8 {
+        match self {
+            BeeRohState::Safe => 0,
+            BeeRohState::Watch => 1,
+            BeeRohState::Critical => 2,
+            BeeRohState::Lockout => 3,
+        }
+    }
+}
+
+/// Raw window fields → normalized [`BeeRiskCoords`].
+///
+/// Every node must derive coordinates from the same corridor table so that
+/// shards computed on different nodes are comparable. Missing readings are
+/// passed as `NaN`; any non-finite reading is treated as missing.
+pub mod normalize {
+    use super::{clamp01, BeeRiskCoords};
+
+    /// Raw measurements for one hive window, as recorded in the shards.
+    #[derive(Copy, Clone, Debug)]
+    pub struct RawHiveWindow {
+        pub brood_temp_c: f64,
+        pub shell_temp_c: f64,
+        pub wbgt_shell_c: f64,
+        pub hive_weight_delta_kg: f64,
+        pub varroa_per_100_bees: f64,
+        pub varroa_sampling_method: VarroaMethod,
+        pub acoustic_stress_index: f64,
+        pub disturbance_events_per_window: f64,
+    }
+
+    /// How the Varroa count was taken; methods differ in reliability.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum VarroaMethod {
+        AlcoholWash,
+        SugarRoll,
+        StickyBoard,
+        Other,
+    }
+
+    impl VarroaMethod {
+        /// Parse the shard spelling (`alcohol_wash`, `sugar_roll`,
+        /// `sticky_board`); anything else is `Other`.
+        pub fn from_name(name: &str) -> Self {
+            match name.trim() {
+                "alcohol_wash" => VarroaMethod::AlcoholWash,
+                "sugar_roll" => VarroaMethod::SugarRoll,
+                "sticky_board" => VarroaMethod::StickyBoard,
+                _ => VarroaMethod::Other,
+            }
+        }
+
+        /// Shard spelling; `Other` is written as `other`.
+        pub fn name(&self) -> &'static str {
+            match self {
+                VarroaMethod::AlcoholWash => "alcohol_wash",
+                VarroaMethod::SugarRoll => "sugar_roll",
+                VarroaMethod::StickyBoard => "sticky_board",
+                VarroaMethod::Other => "other",
+            }
+        }
+    }
+
+    /// Linear ramp from `safe` (r = 0) to `hard` (r = 1). If `hard < safe`
+    /// the ramp runs downward, so lower readings are worse.
+    #[derive(Copy, Clone, Debug)]
+    pub struct RawCorridor {
+        pub safe: f64,
+        pub hard: f64,
+    }
+
+    impl RawCorridor {
+        /// Risk in [0, 1]; `NaN` for a missing (non-finite) reading or a
+        /// zero-width ramp.
+        pub fn risk(&self, x: f64) -> f64 {
+            let span = self.hard - self.safe;
+            if !x.is_finite() || span == 0.0 {
+                return f64::NAN;
+            }
+            clamp01((x - self.safe) / span)
+        }
+    }
+
+    /// Epistemic uncertainty added to `r_sigma` per Varroa sampling method.
+    #[derive(Copy, Clone, Debug)]
+    pub struct MethodUncertainty {
+        pub alcohol_wash: f64,
+        pub sugar_roll: f64,
+        pub sticky_board: f64,
+        pub other: f64,
+    }
+
+    impl MethodUncertainty {
+        pub fn of(&self, method: VarroaMethod) -> f64 {
+            match method {
+                VarroaMethod::AlcoholWash => self.alcohol_wash,
+                VarroaMethod::SugarRoll => self.sugar_roll,
+                VarroaMethod::StickyBoard => self.sticky_board,
+                VarroaMethod::Other => self.other,
+            }
+        }
+    }
+
+    /// Corridor table shared by every node.
+    ///
+    /// - `r_thermal`: worst of brood (overheat and chill), shell and WBGT.
+    /// - `r_parasite`: Varroa per 100 bees.
+    /// - `r_nutrition`: hive weight change over the window.
+    /// - `r_disturb`: worst of acoustic stress and disturbance events.
+    /// - `r_sigma`: sum of `per_missing` for each missing reading, the
+    ///   shell/WBGT disagreement ramp and the Varroa method uncertainty.
+    ///
+    /// A coordinate whose readings are all missing is set to 1: an unknown
+    /// hive is treated as at its corridor edge.
+    #[derive(Copy, Clone, Debug)]
+    pub struct CorridorTable {
+        pub brood_temp_high: RawCorridor,
+        pub brood_temp_low: RawCorridor,
+        pub shell_temp: RawCorridor,
+        pub wbgt_shell: RawCorridor,
+        pub weight_delta: RawCorridor,
+        pub varroa: RawCorridor,
+        pub acoustic_stress: RawCorridor,
+        pub disturbance_events: RawCorridor,
+        /// Ramp on `|shell_temp_c - wbgt_shell_c|`, in °C.
+        pub thermal_disagreement: RawCorridor,
+        pub per_missing: f64,
+        pub varroa_method: MethodUncertainty,
+    }
+
+    /// Worst present risk; 1 when every reading is missing.
+    fn worst(risks: &[f64]) -> f64 {
+        let mut worst: Option<f64> = None;
+        for &r in risks {
+            if !r.is_nan() {
+                worst = Some(worst.map_or(r, |w: f64| w.max(r)));
+            }
+        }
+        worst.unwrap_or(1.0)
+    }
+
+    /// Normalize one raw window with `table`.
+    pub fn normalize_window(raw: &RawHiveWindow, table: &CorridorTable) -> BeeRiskCoords {
+        let r_thermal = worst(&[
+            table.brood_temp_high.risk(raw.brood_temp_c),
+            table.brood_temp_low.risk(raw.brood_temp_c),
+            table.shell_temp.risk(raw.shell_temp_c),
+            table.wbgt_shell.risk(raw.wbgt_shell_c),
+        ]);
+        let r_parasite = worst(&[table.varroa.risk(raw.varroa_per_100_bees)]);
+        let r_nutrition = worst(&[table.weight_delta.risk(raw.hive_weight_delta_kg)]);
+        let r_disturb = worst(&[
+            table.acoustic_stress.risk(raw.acoustic_stress_index),
+            table.disturbance_events.risk(raw.disturbance_events_per_window),
+        ]);
+
+        let readings = [
+            raw.brood_temp_c,
+            raw.shell_temp_c,
+            raw.wbgt_shell_c,
+            raw.hive_weight_delta_kg,
+            raw.varroa_per_100_bees,
+            raw.acoustic_stress_index,
+            raw.disturbance_events_per_window,
+        ];
+        let missing = readings.iter().filter(|x| !x.is_finite()).count() as f64;
+        let disagreement = table
+            .thermal_disagreement
+            .risk((raw.shell_temp_c - raw.wbgt_shell_c).abs());
+        let disagreement = if disagreement.is_nan() { 0.0 } else { disagreement };
+        let method = if !raw.varroa_per_100_bees.is_finite() {
+            0.0
+        } else {
+            table.varroa_method.of(raw.varroa_sampling_method)
+        };
+        let r_sigma = clamp01(missing * table.per_missing + disagreement + method);
+
+        BeeRiskCoords {
+            r_thermal,
+            r_parasite,
+            r_nutrition,
+            r_disturb,
+            r_sigma,
+        }
+    }
+}
+
+/// Reader, writer and auditor for BeeRoH shards (`bee_roh_*.csv`).
+///
+/// Shards use a multi-line layout: a header or record is one logical line
+/// split across physical lines, each continued line ending in `,`, and
+/// records are separated by blank lines. A record ends once it has as many
+/// fields as the header, so single-line CSV whose last cell is empty (a
+/// trailing `,`) reads the same way. Empty numeric cells read as `NaN`.
+#[cfg(feature = "std")]
+pub mod shard {
+    use std::fmt;
+    use std::format;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    use super::normalize::{normalize_window, CorridorTable, RawHiveWindow, VarroaMethod};
+    use super::{compute_bee_roh, permit_actions, BeeRiskCoords, BeeRiskSummary, BeeRiskWeights};
+
+    /// Column groups in the order the shard writer lays them out, one
+    /// physical line per group.
+    pub const LAYOUT: [&[&str]; 7] = [
+        &["node_id", "hive_id", "region", "window_start", "window_end"],
+        &["brood_temp_c", "shell_temp_c", "wbgt_shell_c"],
+        &["hive_weight_kg", "hive_weight_delta_kg"],
+        &["varroa_per_100_bees", "varroa_sampling_method"],
+        &["acoustic_stress_index", "disturbance_events_per_window"],
+        &["r_thermal", "r_parasite", "r_nutrition", "r_disturb", "r_sigma"],
+        &["v_bee", "max_r", "bee_neural_safe", "permit_actions"],
+    ];
+
+    /// One shard record with its stored verdicts.
+    #[derive(Clone, Debug)]
+    pub struct ShardRow {
+        /// Line on which the record starts (1-based).
+        pub line: usize,
+        pub node_id: String,
+        pub hive_id: String,
+        pub region: String,
+        pub window_start: String,
+        pub window_end: String,
+        /// Raw fields; an empty cell reads as `NaN`, as for every number.
+        pub raw: RawHiveWindow,
+        pub hive_weight_kg: f64,
+        pub coords: BeeRiskCoords,
+        pub v_bee: f64,
+        pub max_r: f64,
+        pub bee_neural_safe: bool,
+        pub permit_actions: bool,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ShardErrorKind {
+        MissingHeader,
+        MissingColumn(&'static str),
+        FieldCount { expected: usize, found: usize },
+        BadNumber { column: &'static str, value: String },
+        BadBool { column: &'static str, value: String },
+    }
+
+    /// A shard parse error at a 1-based line.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ShardError {
+        pub line: usize,
+        pub kind: ShardErrorKind,
+    }
+
+    impl fmt::Display for ShardError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "line {}: ", self.line)?;
+            match &self.kind {
+                ShardErrorKind::MissingHeader => write!(f, "missing header"),
+                ShardErrorKind::MissingColumn(c) => write!(f, "missing column `{c}`"),
+                ShardErrorKind::FieldCount { expected, found } => {
+                    write!(f, "expected {expected} fields, found {found}")
+                }
+                ShardErrorKind::BadNumber { column, value } => {
+                    write!(f, "`{column}`: invalid number `{value}`")
+                }
+                ShardErrorKind::BadBool { column, value } => {
+                    write!(f, "`{column}`: invalid boolean `{value}`")
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for ShardError {}
+
+    /// Split text into logical lines: `(start line, fields)`, header first.
+    ///
+    /// A trailing `,` continues the line unless the fields so far already
+    /// number as many as the header's, in which case it is an empty last
+    /// cell. Header names are never empty, so the header always continues.
+    /// A blank line ends any incomplete record.
+    fn logical_lines(text: &str) -> Vec<(usize, Vec<String>)> {
+        let mut out: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut pending: Option<(usize, Vec<String>)> = None;
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                out.extend(pending.take());
+                continue;
+            }
+            let expected = out.first().map_or(usize::MAX, |(_, header)| header.len());
+            let (start, fields) = pending.get_or_insert_with(|| (i + 1, Vec::new()));
+            fields.extend(line.split(',').map(|f| f.trim().to_string()));
+            if line.ends_with(',') && fields.len() < expected {
+                // Drop the empty cell after the continuation comma.
+                fields.pop();
+                continue;
+            }
+            out.push((*start, core::mem::take(fields)));
+            pending = None;
+        }
+        out.extend(pending);
+        out
+    }
+
+    /// Parse a shard; fails on the first malformed record.
+    pub fn read_shard(text: &str) -> Result<Vec<ShardRow>, ShardError> {
+        let mut lines = logical_lines(text).into_iter();
+        let (header_line, header) = lines.next().ok_or(ShardError {
+            line: 1,
+            kind: ShardErrorKind::MissingHeader,
+        })?;
+
+        let mut index = Vec::new();
+        for column in LAYOUT.iter().flat_map(|g| g.iter()) {
+            let i = header.iter().position(|h| h == column).ok_or(ShardError {
+                line: header_line,
+                kind: ShardErrorKind::MissingColumn(column),
+            })?;
+            index.push((*column, i));
+        }
+
+        lines
+            .map(|(line, fields)| {
+                if fields.len() != header.len() {
+                    return Err(ShardError {
+                        line,
+                        kind: ShardErrorKind::FieldCount {
+                            expected: header.len(),
+                            found: fields.len(),
+                        },
+                    });
+                }
+                let get = |column: &str| {
+                    let (_, i) = index.iter().find(|(c, _)| *c == column).copied().unwrap();
+                    fields[i].as_str()
+                };
+                let number = |column: &'static str| {
+                    get(column).parse::<f64>().map_err(|_| ShardError {
+                        line,
+                        kind: ShardErrorKind::BadNumber {
+                            column,
+                            value: get(column).to_string(),
+                        },
+                    })
+                };
+                let raw = |column: &'static str| {
+                    if get(column).is_empty() {
+                        Ok(f64::NAN)
+                    } else {
+                        number(column)
+                    }
+                };
+                let flag = |column: &'static str| match get(column) {
+                    "true" => Ok(true),
+                    "false" => Ok(false),
+                    other => Err(ShardError {
+                        line,
+                        kind: ShardErrorKind::BadBool {
+                            column,
+                            value: other.to_string(),
+                        },
+                    }),
+                };
+
+                Ok(ShardRow {
+                    line,
+                    node_id: get("node_id").to_string(),
+                    hive_id: get("hive_id").to_string(),
+                    region: get("region").to_string(),
+                    window_start: get("window_start").to_string(),
+                    window_end: get("window_end").to_string(),
+                    raw: RawHiveWindow {
+                        brood_temp_c: raw("brood_temp_c")?,
+                        shell_temp_c: raw("shell_temp_c")?,
+                        wbgt_shell_c: raw("wbgt_shell_c")?,
+                        hive_weight_delta_kg: raw("hive_weight_delta_kg")?,
+                        varroa_per_100_bees: raw("varroa_per_100_bees")?,
+                        varroa_sampling_method: VarroaMethod::from_name(get(
+                            "varroa_sampling_method",
+                        )),
+                        acoustic_stress_index: raw("acoustic_stress_index")?,
+                        disturbance_events_per_window: raw("disturbance_events_per_window")?,
+                    },
+                    hive_weight_kg: raw("hive_weight_kg")?,
+                    coords: BeeRiskCoords {
+                        r_thermal: raw("r_thermal")?,
+                        r_parasite: raw("r_parasite")?,
+                        r_nutrition: raw("r_nutrition")?,
+                        r_disturb: raw("r_disturb")?,
+                        r_sigma: raw("r_sigma")?,
+                    },
+                    v_bee: raw("v_bee")?,
+                    max_r: raw("max_r")?,
+                    bee_neural_safe: flag("bee_neural_safe")?,
+                    permit_actions: flag("permit_actions")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Write rows in the shard layout ([`LAYOUT`]); `NaN` numbers are
+    /// written as empty cells, which [`read_shard`] reads back as `NaN`.
+    pub fn write_shard(rows: &[ShardRow]) -> String {
+        let mut out = String::new();
+        push_groups(&mut out, &LAYOUT, |column| column.to_string());
+        for row in rows {
+            out.push('\n');
+            push_groups(&mut out, &LAYOUT, |column| cell(row, column));
+        }
+        out
+    }
+
+    fn push_groups(out: &mut String, groups: &[&[&str]], value: impl Fn(&str) -> String) {
+        for (g, group) in groups.iter().enumerate() {
+            let cells: Vec<String> = group.iter().map(|c| value(c)).collect();
+            out.push_str(&cells.join(","));
+            if g + 1 < groups.len() {
+                out.push(',');
+            }
+            out.push('\n');
+        }
+    }
+
+    fn cell(row: &ShardRow, column: &str) -> String {
+        let num = |x: f64| if x.is_nan() { String::new() } else { format!("{x}") };
+        let r = &row.raw;
+        match column {
+            "node_id" => row.node_id.clone(),
+            "hive_id" => row.hive_id.clone(),
+            "region" => row.region.clone(),
+            "window_start" => row.window_start.clone(),
+            "window_end" => row.window_end.clone(),
+            "brood_temp_c" => num(r.brood_temp_c),
+            "shell_temp_c" => num(r.shell_temp_c),
+            "wbgt_shell_c" => num(r.wbgt_shell_c),
+            "hive_weight_kg" => num(row.hive_weight_kg),
+            "hive_weight_delta_kg" => num(r.hive_weight_delta_kg),
+            "varroa_per_100_bees" => num(r.varroa_per_100_bees),
+            "varroa_sampling_method" => r.varroa_sampling_method.name().to_string(),
+            "acoustic_stress_index" => num(r.acoustic_stress_index),
+            "disturbance_events_per_window" => num(r.disturbance_events_per_window),
+            "r_thermal" => num(row.coords.r_thermal),
+            "r_parasite" => num(row.coords.r_parasite),
+            "r_nutrition" => num(row.coords.r_nutrition),
+            "r_disturb" => num(row.coords.r_disturb),
+            "r_sigma" => num(row.coords.r_sigma),
+            "v_bee" => num(row.v_bee),
+            "max_r" => num(row.max_r),
+            "bee_neural_safe" => row.bee_neural_safe.to_string(),
+            "permit_actions" => row.permit_actions.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// A stored value the kernel does not reproduce.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Mismatch {
+        /// Stored `r_*` differs from normalizing the raw fields.
+        Coord { column: &'static str, stored: f64, computed: f64 },
+        VBee { stored: f64, computed: f64 },
+        MaxR { stored: f64, computed: f64 },
+        BeeNeuralSafe { stored: bool, computed: bool },
+        PermitActions { stored: bool, computed: bool },
+    }
+
+    /// Audit result for one row that disagrees with the kernel.
+    #[derive(Clone, Debug)]
+    pub struct RowAudit {
+        pub line: usize,
+        pub node_id: String,
+        pub hive_id: String,
+        pub window_start: String,
+        pub computed: BeeRiskSummary,
+        pub mismatches: Vec<Mismatch>,
+    }
+
+    /// Recompute every row and report those whose stored values disagree.
+    ///
+    /// `compute_bee_roh` runs on the stored coordinates, so a verdict
+    /// mismatch points at the weights or the producer, not at
+    /// normalization. With a `table`, stored coordinates are also checked
+    /// against [`normalize_window`]. `permit_actions` compares each row with
+    /// the previous row of the same node and hive; a hive's first row is
+    /// compared with itself. Numbers match within `tolerance`.
+    pub fn audit_rows(
+        rows: &[ShardRow],
+        weights: &BeeRiskWeights,
+        table: Option<&CorridorTable>,
+        tolerance: f64,
+    ) -> Vec<RowAudit> {
+        // Two empty (NaN) values agree, so the writer's own output audits clean.
+        let near = |a: f64, b: f64| (a.is_nan() && b.is_nan()) || (a - b).abs() <= tolerance;
+        let mut last: Vec<(&str, &str, BeeRiskSummary)> = Vec::new();
+        let mut audits = Vec::new();
+
+        for row in rows {
+            let mut mismatches = Vec::new();
+
+            if let Some(table) = table {
+                let n = normalize_window(&row.raw, table);
+                let pairs = [
+                    ("r_thermal", row.coords.r_thermal, n.r_thermal),
+                    ("r_parasite", row.coords.r_parasite, n.r_parasite),
+                    ("r_nutrition", row.coords.r_nutrition, n.r_nutrition),
+                    ("r_disturb", row.coords.r_disturb, n.r_disturb),
+                    ("r_sigma", row.coords.r_sigma, n.r_sigma),
+                ];
+                for (column, stored, computed) in pairs {
+                    if !near(stored, computed) {
+                        mismatches.push(Mismatch::Coord { column, stored, computed });
+                    }
+                }
+            }
+
+            let summary = compute_bee_roh(&row.coords, weights);
+            if !near(row.v_bee, summary.v_bee) {
+                mismatches.push(Mismatch::VBee { stored: row.v_bee, computed: summary.v_bee });
+            }
+            if !near(row.max_r, summary.max_r) {
+                mismatches.push(Mismatch::MaxR { stored: row.max_r, computed: summary.max_r });
+            }
+            if row.bee_neural_safe != summary.bee_neural_safe {
+                mismatches.push(Mismatch::BeeNeuralSafe {
+                    stored: row.bee_neural_safe,
+                    computed: summary.bee_neural_safe,
+                });
+            }
+
+            let key = (row.node_id.as_str(), row.hive_id.as_str());
+            let prev = match last.iter_mut().find(|(n, h, _)| (*n, *h) == key) {
+                Some((_, _, prev)) => core::mem::replace(prev, summary),
+                None => {
+                    last.push((key.0, key.1, summary));
+                    summary
+                }
+            };
+            let permit = permit_actions(&prev, &summary, weights);
+            if row.permit_actions != permit {
+                mismatches.push(Mismatch::PermitActions {
+                    stored: row.permit_actions,
+                    computed: permit,
+                });
+            }
+
+            if !mismatches.is_empty() {
+                audits.push(RowAudit {
+                    line: row.line,
+                    node_id: row.node_id.clone(),
+                    hive_id: row.hive_id.clone(),
+                    window_start: row.window_start.clone(),
+                    computed: summary,
+                    mismatches,
+                });
+            }
+        }
+        audits
+    }
+}
+
+/// Fit weights and thresholds from labeled historical windows.
+pub mod calibration {
+    use core::fmt;
+
+    use super::{compute_bee_roh, BeeRiskCoords, BeeRiskWeights, WeightError};
+
+    /// A historical window with its outcome.
+    #[derive(Copy, Clone, Debug)]
+    pub struct LabeledWindow {
+        pub coords: BeeRiskCoords,
+        /// The colony was later lost (true) or survived (false).
+        pub lost: bool,
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct CalibrationConfig {
+        /// Floor added to every weight before normalizing, so no coordinate
+        /// is dropped on limited data.
+        pub min_weight: f64,
+        /// Relative margin placing v_crit below the lowest V_bee of a lost
+        /// colony.
+        pub margin: f64,
+    }
+
+    impl Default for CalibrationConfig {
+        fn default() -> Self {
+            CalibrationConfig {
+                min_weight: 0.02,
+                margin: 0.05,
+            }
+        }
+    }
+
+    /// Calibrated weights and how they classify the history.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Calibration {
+        pub weights: BeeRiskWeights,
+        /// Lost colonies whose window stays at or below v_crit.
+        pub missed_losses: usize,
+        /// Surviving colonies whose window is above v_crit.
+        pub false_alarms: usize,
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum CalibrationError {
+        /// The current weights are invalid.
+        Weights(WeightError),
+        /// Both outcomes are needed to separate them.
+        NeedBothLabels { lost: usize, survived: usize },
+        /// A history window (by index) has a non-finite coordinate.
+        NonFiniteWindow { index: usize },
+        /// A lost window (by index) has zero risk on every coordinate, so no
+        /// weights can place it above v_crit.
+        LostWindowAtZeroRisk { index: usize },
+        /// The fit produced invalid weights, e.g. a zero v_crit.
+        Fitted(WeightError),
+    }
+
+    impl fmt::Display for CalibrationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                CalibrationError::Weights(e) => write!(f, "current weights: {e}"),
+                CalibrationError::NeedBothLabels { lost, survived } => write!(
+                    f,
+                    "need lost and surviving windows, got {lost} lost and {survived} survived"
+                ),
+                CalibrationError::NonFiniteWindow { index } => {
+                    write!(f, "window {index} has a non-finite coordinate")
+                }
+                CalibrationError::LostWindowAtZeroRisk { index } => {
+                    write!(f, "lost window {index} has zero risk on every coordinate")
+                }
+                CalibrationError::Fitted(e) => write!(f, "fitted weights: {e}"),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for CalibrationError {}
+
+    impl From<WeightError> for CalibrationError {
+        fn from(e: WeightError) -> Self {
+            CalibrationError::Weights(e)
+        }
+    }
+
+    fn squares(c: &BeeRiskCoords) -> [f64; 5] {
+        [c.r_thermal, c.r_parasite, c.r_nutrition, c.r_disturb, c.r_sigma]
+            .map(|r| super::clamp01(r) * super::clamp01(r))
+    }
+
+    /// Fit weights and thresholds to `history`, tightening only.
+    ///
+    /// The fitted direction `u` weights each coordinate by the excess of mean
+    /// r_x² in lost over surviving windows (floored at 0, plus `min_weight`),
+    /// normalized to sum to 1. The result is `s * current + (1 - s) * u`
+    /// with v_safe and v_crit scaled by `s`, where `s` in [0, 1] is the
+    /// largest value that keeps every lost window `margin` above v_crit.
+    ///
+    /// Every fitted weight is at least `s` times its current value, so
+    /// V_bee / v_crit and V_bee / v_safe never drop below their values under
+    /// `current` for any coordinates: a window the current gate flags stays
+    /// flagged, and calibration can never loosen a deployed gate. r_hard is
+    /// kept.
+    pub fn calibrate(
+        history: &[LabeledWindow],
+        current: &BeeRiskWeights,
+        config: &CalibrationConfig,
+    ) -> Result<Calibration, CalibrationError> {
+        current.validate()?;
+        for (index, h) in history.iter().enumerate() {
+            let c = &h.coords;
+            let r = [c.r_thermal, c.r_parasite, c.r_nutrition, c.r_disturb, c.r_sigma];
+            if r.iter().any(|x| !x.is_finite()) {
+                return Err(CalibrationError::NonFiniteWindow { index });
+            }
+            if h.lost && squares(c).iter().all(|&r2| r2 == 0.0) {
+                return Err(CalibrationError::LostWindowAtZeroRisk { index });
+            }
+        }
+
+        let lost = history.iter().filter(|h| h.lost).count();
+        let survived = history.len() - lost;
+        if lost == 0 || survived == 0 {
+            return Err(CalibrationError::NeedBothLabels { lost, survived });
+        }
+
+        let mut mean_lost = [0.0; 5];
+        let mut mean_survived = [0.0; 5];
+        for h in history {
+            let (mean, n) = if h.lost {
+                (&mut mean_lost, lost)
+            } else {
+                (&mut mean_survived, survived)
+            };
+            for (m, r2) in mean.iter_mut().zip(squares(&h.coords)) {
+                *m += r2 / n as f64;
+            }
+        }
+        let mut u = [0.0; 5];
+        for (i, u) in u.iter_mut().enumerate() {
+            *u = (mean_lost[i] - mean_survived[i]).max(0.0) + config.min_weight.max(0.0);
+        }
+        let sum: f64 = u.iter().sum();
+        let cur = current.weights();
+        let u = if sum > 0.0 { u.map(|x| x / sum) } else { cur };
+
+        // Lost window j needs s * v_crit <= keep * (s * a_j + (1 - s) * b_j),
+        // with a_j and b_j its V_bee under `current` and `u`.
+        let v = |w: &[f64; 5], h: &LabeledWindow| -> f64 {
+            w.iter().zip(squares(&h.coords)).map(|(w, r2)| w * r2).sum()
+        };
+        let keep = 1.0 - config.margin;
+        let mut s: f64 = 1.0;
+        for h in history.iter().filter(|h| h.lost) {
+            let (a, b) = (v(&cur, h), v(&u, h));
+            let k = current.v_crit - keep * (a - b);
+            if k > 0.0 {
+                s = s.min(keep * b / k);
+            }
+        }
+        let s = s.max(0.0);
+        let w: [f64; 5] = core::array::from_fn(|i| s * cur[i] + (1.0 - s) * u[i]);
+
+        let fitted = BeeRiskWeights {
+            w_thermal: w[0],
+            w_parasite: w[1],
+            w_nutrition: w[2],
+            w_disturb: w[3],
+            w_sigma: w[4],
+            v_safe: s * current.v_safe,
+            v_crit: s * current.v_crit,
+            r_hard: current.r_hard,
+        }
+        .validated()
+        .map_err(CalibrationError::Fitted)?;
+
+        let mut missed_losses = 0;
+        let mut false_alarms = 0;
+        for h in history {
+            let critical = compute_bee_roh(&h.coords, &fitted).v_bee > fitted.v_crit;
+            match (h.lost, critical) {
+                (true, false) => missed_losses += 1,
+                (false, true) => false_alarms += 1,
+                _ => {}
+            }
+        }
+
+        Ok(Calibration {
+            weights: fitted,
+            missed_losses,
+            false_alarms,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +1209,392 @@ mod tests {
         assert!(permit_actions(&prev, &curr_safe, &w));
         assert!(!permit_actions(&prev, &curr_unsafe, &w));
     }
+
+    fn table() -> normalize::CorridorTable {
+        use normalize::{CorridorTable, MethodUncertainty, RawCorridor};
+        let ramp = |safe, hard| RawCorridor { safe, hard };
+        CorridorTable {
+            brood_temp_high: ramp(35.5, 37.5),
+            brood_temp_low: ramp(33.5, 31.0),
+            shell_temp: ramp(39.0, 47.0),
+            wbgt_shell: ramp(39.0, 47.0),
+            weight_delta: ramp(0.0, -1.0),
+            varroa: ramp(1.0, 6.0),
+            acoustic_stress: ramp(0.1, 0.6),
+            disturbance_events: ramp(0.0, 4.0),
+            thermal_disagreement: ramp(0.5, 5.0),
+            per_missing: 0.15,
+            varroa_method: MethodUncertainty {
+                alcohol_wash: 0.0,
+                sugar_roll: 0.05,
+                sticky_board: 0.15,
+                other: 0.25,
+            },
+        }
+    }
+
+    fn phoenix_row_2() -> normalize::RawHiveWindow {
+        normalize::RawHiveWindow {
+            brood_temp_c: 36.5,
+            shell_temp_c: 41.0,
+            wbgt_shell_c: 41.2,
+            hive_weight_delta_kg: -0.20,
+            varroa_per_100_bees: 4.5,
+            varroa_sampling_method: normalize::VarroaMethod::from_name("alcohol_wash"),
+            acoustic_stress_index: 0.28,
+            disturbance_events_per_window: 1.0,
+        }
+    }
+
+    #[test]
+    fn raw_window_normalizes_through_corridor_table() {
+        let c = normalize::normalize_window(&phoenix_row_2(), &table());
+        // Brood 36.5 is halfway up its 35.5..37.5 ramp; shell and WBGT less.
+        assert!((c.r_thermal - 0.5).abs() < 1e-12);
+        assert!((c.r_parasite - 0.7).abs() < 1e-12);
+        assert!((c.r_nutrition - 0.2).abs() < 1e-12);
+        assert!((c.r_disturb - 0.36).abs() < 1e-12);
+        assert_eq!(c.r_sigma, 0.0);
+
+        // A chilled brood nest is as risky as an overheated one.
+        let mut cold = phoenix_row_2();
+        cold.brood_temp_c = 32.25;
+        assert!((normalize::normalize_window(&cold, &table()).r_thermal - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn missing_readings_are_conservative() {
+        let mut raw = phoenix_row_2();
+        raw.varroa_per_100_bees = f64::NAN;
+        raw.shell_temp_c = f64::NAN;
+        raw.varroa_sampling_method = normalize::VarroaMethod::StickyBoard;
+        let c = normalize::normalize_window(&raw, &table());
+        assert_eq!(c.r_parasite, 1.0);
+        assert!((c.r_thermal - 0.5).abs() < 1e-12);
+        // Two missing readings; the method no longer matters without a count.
+        assert!((c.r_sigma - 0.3).abs() < 1e-12);
+
+        // A broken sensor reporting infinity is missing too, not mite-free.
+        let mut raw = phoenix_row_2();
+        raw.varroa_per_100_bees = f64::NEG_INFINITY;
+        raw.brood_temp_c = f64::INFINITY;
+        let c = normalize::normalize_window(&raw, &table());
+        assert_eq!(c.r_parasite, 1.0);
+        assert!((c.r_sigma - 0.3).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn phoenix_shard_round_trips_and_audits() {
+        use shard::{audit_rows, read_shard, write_shard, Mismatch};
+
+        let text = include_str!("../../../data/qpudatashards/bee_roh_phoenix_2026_v1.csv");
+        let rows = read_shard(text).expect("shard parses");
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].line, rows[1].line), (9, 17));
+        assert_eq!(rows[1].raw.varroa_per_100_bees, 4.5);
+        assert_eq!(rows[1].coords.r_thermal, 0.55);
+        assert!(!rows[1].permit_actions);
+
+        let again = read_shard(&write_shard(&rows)).expect("written shard parses");
+        assert_eq!(again.len(), rows.len());
+        for (a, b) in rows.iter().zip(&again) {
+            assert_eq!(a.window_start, b.window_start);
+            assert_eq!(a.raw.hive_weight_delta_kg, b.raw.hive_weight_delta_kg);
+            assert_eq!(a.coords.r_sigma, b.coords.r_sigma);
+            assert_eq!(a.v_bee, b.v_bee);
+            assert_eq!(a.permit_actions, b.permit_actions);
+        }
+
+        // The stored verdicts agree with the kernel, but the stored V_bee
+        // does not follow from the stored coordinates and these weights.
+        let audits = audit_rows(&rows, &default_weights(), None, 0.005);
+        assert_eq!(audits.len(), 2);
+        for audit in &audits {
+            assert!(matches!(audit.mismatches.as_slice(), [Mismatch::VBee { .. }]));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn single_line_shard_with_empty_last_cell_and_nan_round_trip() {
+        use shard::{audit_rows, read_shard, write_shard};
+
+        let text = "\
+node_id,hive_id,region,window_start,window_end,brood_temp_c,shell_temp_c,wbgt_shell_c,hive_weight_kg,hive_weight_delta_kg,varroa_per_100_bees,varroa_sampling_method,disturbance_events_per_window,r_thermal,r_parasite,r_nutrition,r_disturb,r_sigma,v_bee,max_r,bee_neural_safe,permit_actions,acoustic_stress_index
+N1,H1,R,t0,t1,35.2,38.9,39.0,52.4,0.10,2.5,alcohol_wash,0,0.20,0.15,0.10,0.08,0.05,0.085,0.20,true,true,
+N1,H1,R,t1,t2,35.2,38.9,39.0,52.4,0.10,2.5,alcohol_wash,0,0.20,0.15,0.10,0.08,0.05,0.085,0.20,true,true,0.12
+";
+        let mut rows = read_shard(text).expect("single-line shard parses");
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].line, rows[1].line), (2, 3));
+        assert!(rows[0].raw.acoustic_stress_index.is_nan());
+        assert_eq!(rows[1].raw.acoustic_stress_index, 0.12);
+
+        // Unknown coordinates and verdict numbers survive a round trip.
+        rows[0].coords.r_thermal = f64::NAN;
+        rows[0].v_bee = f64::NAN;
+        rows[0].max_r = f64::NAN;
+        rows[0].bee_neural_safe = false;
+        rows[0].permit_actions = false;
+        let again = read_shard(&write_shard(&rows)).expect("written shard parses");
+        assert_eq!(again.len(), 2);
+        assert!(again[0].coords.r_thermal.is_nan());
+        assert!(again[0].v_bee.is_nan() && again[0].max_r.is_nan());
+        assert!(again[0].raw.acoustic_stress_index.is_nan());
+        assert_eq!(again[1].coords.r_thermal, 0.20);
+
+        // Empty stored verdicts match an unscoreable recomputation.
+        let audits = audit_rows(&again, &default_weights(), None, 0.005);
+        assert!(audits.iter().all(|a| a.line != again[0].line), "{audits:?}");
+    }
+
+    fn summary(v_bee: f64, max_r: f64) -> BeeRiskSummary {
+        let w = default_weights();
+        BeeRiskSummary {
+            v_bee,
+            max_r,
+            bee_neural_safe: v_bee <= w.v_safe && max_r <= w.r_hard,
+        }
+    }
+
+    #[test]
+    fn monitor_treats_unscored_windows_as_watch() {
+        let w = default_weights();
+        let mut m = BeeRohMonitor::new(BeeRohHysteresis::default());
+        for _ in 0..3 {
+            m.update(&summary(0.05, 0.3), &w);
+        }
+        assert_eq!(m.state(), BeeRohState::Safe);
+
+        assert_eq!(m.update(&summary(f64::NAN, 0.3), &w), BeeRohState::Watch);
+        assert!(!m.permits_actions(&w));
+        // An unknown window never counts toward leaving Watch.
+        for _ in 0..5 {
+            assert_eq!(m.update(&summary(0.05, f64::NAN), &w), BeeRohState::Watch);
+        }
+        assert!(!m.permits_actions(&w));
+        assert_eq!(m.update(&summary(0.05, 0.3), &w), BeeRohState::Safe);
+    }
+
+    #[test]
+    fn monitor_does_not_flap_around_v_safe() {
+        let w = default_weights();
+        let mut m = BeeRohMonitor::new(BeeRohHysteresis::default());
+        for _ in 0..3 {
+            m.update(&summary(0.05, 0.3), &w);
+        }
+        assert_eq!(m.state(), BeeRohState::Safe);
+        assert!(m.permits_actions(&w));
+
+        // Oscillating just either side of v_safe = 0.10 holds Watch.
+        for v in [0.11, 0.095, 0.105, 0.095, 0.095, 0.095] {
+            assert_eq!(m.update(&summary(v, 0.3), &w), BeeRohState::Watch);
+            assert!(!m.permits_actions(&w));
+        }
+        // Clearing the 10 % margin for the dwell time returns to Safe.
+        assert_eq!(m.update(&summary(0.085, 0.3), &w), BeeRohState::Safe);
+    }
+
+    #[test]
+    fn critical_persistence_and_hard_breach_lock_out() {
+        let w = default_weights();
+        let config = BeeRohHysteresis::default();
+        let mut m = BeeRohMonitor::new(config);
+        assert_eq!(m.update(&summary(0.35, 0.6), &w), BeeRohState::Critical);
+        assert_eq!(m.update(&summary(0.35, 0.6), &w), BeeRohState::Critical);
+        assert_eq!(m.update(&summary(0.35, 0.6), &w), BeeRohState::Lockout);
+
+        let mut m = BeeRohMonitor::new(config);
+        assert_eq!(m.update(&summary(0.05, 0.9), &w), BeeRohState::Lockout);
+        // Lockout needs six consecutive clearly safe windows; a relapse
+        // restarts the count.
+        for _ in 0..5 {
+            assert_eq!(m.update(&summary(0.05, 0.3), &w), BeeRohState::Lockout);
+        }
+        assert_eq!(m.update(&summary(0.098, 0.3), &w), BeeRohState::Lockout);
+        for _ in 0..5 {
+            assert_eq!(m.update(&summary(0.05, 0.3), &w), BeeRohState::Lockout);
+        }
+        assert_eq!(m.update(&summary(0.05, 0.3), &w), BeeRohState::Safe);
+
+        // Even with no clearing run configured, Lockout only exits on a
+        // clearly safe window.
+        let mut m = BeeRohMonitor::new(BeeRohHysteresis {
+            lockout_clear_windows: 0,
+            ..config
+        });
+        assert_eq!(m.update(&summary(0.05, 0.9), &w), BeeRohState::Lockout);
+        assert_eq!(m.update(&summary(0.2, 0.3), &w), BeeRohState::Lockout);
+        assert_eq!(m.update(&summary(0.05, 0.3), &w), BeeRohState::Safe);
+    }
+
+    #[test]
+    fn weights_are_validated() {
+        assert!(default_weights().validated().is_ok());
+
+        let mut w = default_weights();
+        w.w_sigma = -0.1;
+        assert_eq!(
+            w.validate(),
+            Err(WeightError::NegativeWeight { field: "w_sigma", value: -0.1 })
+        );
+
+        let mut w = default_weights();
+        w.w_thermal = 0.5;
+        assert!(matches!(w.validate(), Err(WeightError::WeightSum { .. })));
+
+        let mut w = default_weights();
+        w.v_safe = 0.4;
+        assert_eq!(
+            w.validate(),
+            Err(WeightError::Threshold { field: "v_safe", value: 0.4 })
+        );
+
+        let mut w = default_weights();
+        w.r_hard = f64::NAN;
+        assert_eq!(w.validate(), Err(WeightError::NonFinite { field: "r_hard" }));
+    }
+
+    #[test]
+    fn calibration_weights_discriminating_coordinates_and_only_tightens() {
+        use calibration::{calibrate, CalibrationConfig, CalibrationError, LabeledWindow};
+
+        let window = |r_parasite: f64, r_thermal: f64, lost: bool| LabeledWindow {
+            coords: BeeRiskCoords {
+                r_thermal,
+                r_parasite,
+                r_nutrition: 0.2,
+                r_disturb: 0.1,
+                r_sigma: 0.1,
+            },
+            lost,
+        };
+        // Losses follow Varroa; heat is the same in both groups.
+        let history = [
+            window(0.9, 0.4, true),
+            window(0.8, 0.2, true),
+            window(0.2, 0.4, false),
+            window(0.1, 0.2, false),
+            window(0.3, 0.3, false),
+        ];
+        let current = default_weights();
+        let cal = calibrate(&history, &current, &CalibrationConfig::default()).unwrap();
+        let w = cal.weights;
+
+        assert!(w.w_parasite > w.w_thermal);
+        assert!(w.w_parasite > current.w_parasite && w.w_thermal < current.w_thermal);
+        assert!((w.weights().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(w.v_crit <= current.v_crit && w.v_safe <= current.v_safe);
+        assert!(w.v_safe <= w.v_crit);
+        assert_eq!(w.r_hard, current.r_hard);
+        assert_eq!((cal.missed_losses, cal.false_alarms), (0, 0));
+
+        assert_eq!(
+            calibrate(&history[2..], &current, &CalibrationConfig::default()).unwrap_err(),
+            CalibrationError::NeedBothLabels { lost: 0, survived: 3 }
+        );
+    }
+
+    #[test]
+    fn calibration_never_clears_a_window_the_current_gate_flags() {
+        use calibration::{calibrate, CalibrationConfig, LabeledWindow};
+
+        let window = |r_parasite: f64, lost: bool| LabeledWindow {
+            coords: BeeRiskCoords {
+                r_thermal: 0.2,
+                r_parasite,
+                r_nutrition: 0.1,
+                r_disturb: 0.1,
+                r_sigma: 0.1,
+            },
+            lost,
+        };
+        // Only Varroa separates the outcomes, and losses sit below v_crit.
+        let history = [
+            window(0.7, true),
+            window(0.75, true),
+            window(0.1, false),
+            window(0.2, false),
+        ];
+        let current = default_weights();
+        let w = calibrate(&history, &current, &CalibrationConfig::default())
+            .unwrap()
+            .weights;
+        assert!(w.w_thermal < current.w_thermal);
+
+        let heat = BeeRiskCoords {
+            r_thermal: 0.75,
+            r_parasite: 0.0,
+            r_nutrition: 0.0,
+            r_disturb: 0.0,
+            r_sigma: 0.0,
+        };
+        assert!(!compute_bee_roh(&heat, &current).bee_neural_safe);
+        assert!(!compute_bee_roh(&heat, &w).bee_neural_safe);
+
+        let steps = [0.0, 0.25, 0.5, 0.75, 1.0];
+        for rt in steps {
+            for rp in steps {
+                for rn in steps {
+                    for rd in steps {
+                        for rs in steps {
+                            let c = BeeRiskCoords {
+                                r_thermal: rt,
+                                r_parasite: rp,
+                                r_nutrition: rn,
+                                r_disturb: rd,
+                                r_sigma: rs,
+                            };
+                            let before = compute_bee_roh(&c, &current);
+                            let after = compute_bee_roh(&c, &w);
+                            if before.v_bee > current.v_safe {
+                                assert!(after.v_bee > w.v_safe, "{c:?} became safe");
+                            }
+                            if before.v_bee > current.v_crit {
+                                assert!(after.v_bee > w.v_crit, "{c:?} left critical");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn calibration_rejects_unusable_history_before_fitting() {
+        use calibration::{calibrate, CalibrationConfig, CalibrationError, LabeledWindow};
+
+        let window = |r_parasite: f64, lost: bool| LabeledWindow {
+            coords: BeeRiskCoords {
+                r_thermal: 0.0,
+                r_parasite,
+                r_nutrition: 0.0,
+                r_disturb: 0.0,
+                r_sigma: 0.0,
+            },
+            lost,
+        };
+        let current = default_weights();
+        let config = CalibrationConfig::default();
+
+        let nan = [window(0.8, true), window(f64::NAN, false)];
+        assert_eq!(
+            calibrate(&nan, &current, &config).unwrap_err(),
+            CalibrationError::NonFiniteWindow { index: 1 }
+        );
+        let zero = [window(0.8, true), window(0.0, true), window(0.1, false)];
+        assert_eq!(
+            calibrate(&zero, &current, &config).unwrap_err(),
+            CalibrationError::LostWindowAtZeroRisk { index: 1 }
+        );
+
+        // A fit that cannot keep v_crit positive is blamed on the fit.
+        let history = [window(0.8, true), window(0.1, false)];
+        let all_margin = CalibrationConfig { margin: 1.0, ..config };
+        assert!(matches!(
+            calibrate(&history, &current, &all_margin).unwrap_err(),
+            CalibrationError::Fitted(WeightError::Threshold { field: "v_safe", .. })
+        ));
+    }
 }
@@ -2,23 +2,48 @@
 // Knowledge-Factor: 0.93, Eco-impact: 0.90, Risk-of-harm: 0.13
 
 pub mod bands {
+    use serde::{Deserialize, Serialize};
+
+    /// Safe/gold/hard edges of one tail of a corridor, in the metric's raw units.
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct BandEdges {
+        pub safe: f64,
+        pub gold: f64,
+        pub hard: f64,
+    }
+
+    /// Which side(s) of a corridor are dangerous.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum BandDirection {
+        /// Risk grows above `safe`: `safe <= gold <= hard` (temperature, toxins).
+        HigherIsWorse,
+        /// Risk grows below `safe`: `hard <= gold <= safe` (forage radius, stores).
+        LowerIsWorse,
+        /// `safe`/`gold`/`hard` bound the upper tail and `lower` the lower
+        /// tail, with `lower.hard <= lower.gold <= lower.safe` (humidity).
+        TwoSided { lower: BandEdges },
+    }
+
     /// Corridor bands for a single bee-relevant metric (dimensionless risk 0–1).
     #[derive(Clone, Debug)]
     pub struct CorridorBands {
-        pub var_id: &'static str,
-        pub units: &'static str,      // e.g., "dimensionless", "C", "ug/m3"
-        pub safe: f64,                // safe band upper bound (<= gold)
-        pub gold: f64,                // preferred band upper bound (<= hard)
-        pub hard: f64,                // hard limit (must not be exceeded)
+        pub var_id: String,
+        pub units: String,            // e.g., "dimensionless", "C", "ug/m3"
+        pub safe: f64,                // safe band edge (inside gold)
+        pub gold: f64,                // preferred band edge (inside hard)
+        pub hard: f64,                // hard limit (must not be crossed)
+        pub direction: BandDirection, // which tail(s) carry risk
         pub weight: f64,              // contribution to residual V
         pub lyap_channel: u32,        // for diagnostics
         pub mandatory: bool,          // true => no corridor, no build
     }
 
     impl CorridorBands {
+        /// Higher-is-worse corridor; see [`Self::lower_is_worse`] and
+        /// [`Self::with_lower_tail`] for the other directions.
         pub fn new(
-            var_id: &'static str,
-            units: &'static str,
+            var_id: impl Into<String>,
+            units: impl Into<String>,
             safe: f64,
             gold: f64,
             hard: f64,
@@ -27,49 +52,196 @@ pub mod bands {
             mandatory: bool,
         ) -> Self {
             Self {
-                var_id,
-                units,
+                var_id: var_id.into(),
+                units: units.into(),
                 safe,
                 gold,
                 hard,
+                direction: BandDirection::HigherIsWorse,
                 weight,
                 lyap_channel,
                 mandatory,
             }
         }
+
+        /// Reinterpret `safe`/`gold`/`hard` as a lower tail (`hard <= gold <= safe`).
+        pub fn lower_is_worse(mut self) -> Self {
+            self.direction = BandDirection::LowerIsWorse;
+            self
+        }
+
+        /// Keep `safe`/`gold`/`hard` as the upper tail and add a lower tail.
+        pub fn with_lower_tail(mut self, safe: f64, gold: f64, hard: f64) -> Self {
+            self.direction = BandDirection::TwoSided {
+                lower: BandEdges { safe, gold, hard },
+            };
+            self
+        }
+
+        /// Edges of the upper (higher-is-worse) tail, if any.
+        pub fn upper_edges(&self) -> Option<BandEdges> {
+            match self.direction {
+                BandDirection::LowerIsWorse => None,
+                _ => Some(BandEdges {
+                    safe: self.safe,
+                    gold: self.gold,
+                    hard: self.hard,
+                }),
+            }
+        }
+
+        /// Edges of the lower (lower-is-worse) tail, if any.
+        pub fn lower_edges(&self) -> Option<BandEdges> {
+            match self.direction {
+                BandDirection::HigherIsWorse => None,
+                BandDirection::LowerIsWorse => Some(BandEdges {
+                    safe: self.safe,
+                    gold: self.gold,
+                    hard: self.hard,
+                }),
+                BandDirection::TwoSided { lower } => Some(lower),
+            }
+        }
+
+        /// Gold edge in risk space (r_gold in [0, 1]) for the tail a reading of
+        /// `measured` falls on. A two-sided band picks the tail on the
+        /// reading's side of the midpoint between its safe edges.
+        pub fn r_gold(&self, measured: f64) -> f64 {
+            let (lower, upper) = (self.lower_edges(), self.upper_edges());
+            let on_upper = match (lower, upper) {
+                (Some(lo), Some(hi)) => measured >= 0.5 * (lo.safe + hi.safe),
+                (_, hi) => hi.is_some(),
+            };
+            if on_upper {
+                upper.map_or(1.0, |e| edge_risk(e.gold - e.safe, e.hard - e.safe))
+            } else {
+                lower.map_or(1.0, |e| edge_risk(e.safe - e.gold, e.safe - e.hard))
+            }
+        }
+    }
+
+    /// Position of an edge `offset` past the safe edge on a tail `span` wide.
+    fn edge_risk(offset: f64, span: f64) -> f64 {
+        if span > 0.0 {
+            (offset / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
     }
 }
 
 pub mod risk {
     use super::bands::CorridorBands;
+    use super::hive::EcoBand;
 
     /// Single normalized risk coordinate r_x in [0, 1] with uncertainty.
     #[derive(Clone, Debug)]
     pub struct RiskCoord {
-        pub var_id: &'static str,
+        pub var_id: String,
         pub value: f64,   // normalized risk coordinate r_x
-        pub sigma: f64,   // uncertainty
+        pub sigma: f64,   // 1-sigma uncertainty of r_x (risk units)
+        pub r_gold: f64,  // gold band edge in risk space, for the active tail
         pub bands: CorridorBands,
     }
 
+    impl RiskCoord {
+        /// Coordinate for a raw reading with 1-sigma uncertainty `sigma`.
+        pub fn new(measured: f64, sigma: f64, bands: &CorridorBands) -> Self {
+            let (value, sigma) = to_risk_with_sigma(measured, sigma, bands);
+            Self {
+                var_id: bands.var_id.clone(),
+                value,
+                sigma,
+                r_gold: bands.r_gold(measured),
+                bands: bands.clone(),
+            }
+        }
+
+        /// `r_x + k * sigma`, capped at 1.
+        pub fn upper_bound(&self, k: f64) -> f64 {
+            (self.value + k * self.sigma).min(1.0)
+        }
+    }
+
     /// Aggregate residual V_t and decision flags for a hive step.
     #[derive(Clone, Debug)]
     pub struct Residual {
         pub vt: f64,
+        /// 1-sigma uncertainty of V_t, from the coordinate sigmas.
+        pub vt_sigma: f64,
         pub coords: Vec<RiskCoord>,
         pub derate: bool,
         pub stop: bool,
     }
 
-    /// Piecewise-linear normalization into r_x using safegoldhard bands.
+    impl Residual {
+        /// Band implied by the decision flags: Critical when a hard edge is
+        /// reached (stop), Warning beyond a gold edge (derate), else Safe.
+        pub fn eco_band(&self) -> EcoBand {
+            if self.stop {
+                EcoBand::Critical
+            } else if self.derate {
+                EcoBand::Warning
+            } else {
+                EcoBand::Safe
+            }
+        }
+
+        /// Confidence interval `V_t ± k * vt_sigma`, floored at 0.
+        pub fn vt_interval(&self, k: f64) -> (f64, f64) {
+            ((self.vt - k * self.vt_sigma).max(0.0), self.vt + k * self.vt_sigma)
+        }
+    }
+
+    /// Piecewise-linear normalization into r_x using safe→gold→hard bands.
+    ///
+    /// Each dangerous tail maps its safe edge to 0 and its hard edge to 1; a
+    /// two-sided corridor takes the larger of the two tails. A non-finite
+    /// reading cannot be placed in the corridor and maps to 1 (hard edge).
     pub fn to_risk(measured: f64, bands: &CorridorBands) -> f64 {
-        if measured <= bands.safe {
+        if !measured.is_finite() {
+            return 1.0;
+        }
+        let upper = bands
+            .upper_edges()
+            .map_or(0.0, |e| tail_risk(measured - e.safe, e.hard - e.safe));
+        let lower = bands
+            .lower_edges()
+            .map_or(0.0, |e| tail_risk(e.safe - measured, e.safe - e.hard));
+        upper.max(lower)
+    }
+
+    /// Risk coordinate and its 1-sigma uncertainty for a reading with raw
+    /// measurement uncertainty `sigma` (same units as `measured`).
+    ///
+    /// The risk sigma is how far risk can rise within `measured ± sigma`:
+    /// the worse of the two one-sided spreads, since risk is monotone on each
+    /// tail and the worst case lies at an end. It is 0 deep inside the safe
+    /// interior or beyond the hard edge, and never shrinks as `sigma` grows,
+    /// even across both tails of a two-sided band. A non-finite `sigma`
+    /// gives a risk sigma of 1.
+    pub fn to_risk_with_sigma(measured: f64, sigma: f64, bands: &CorridorBands) -> (f64, f64) {
+        let value = to_risk(measured, bands);
+        if !sigma.is_finite() {
+            return (value, 1.0);
+        }
+        let sigma = sigma.abs();
+        if sigma == 0.0 {
+            return (value, 0.0);
+        }
+        let worst = to_risk(measured + sigma, bands).max(to_risk(measured - sigma, bands));
+        (value, (worst - value).max(0.0))
+    }
+
+    /// Risk for a reading `excess` past the safe edge of a tail `span` wide.
+    fn tail_risk(excess: f64, span: f64) -> f64 {
+        if excess <= 0.0 {
             0.0
-        } else if measured >= bands.hard {
+        } else if excess >= span {
             1.0
         } else {
             // Map [safe, hard] -> [0, 1]
-            (measured - bands.safe) / (bands.hard - bands.safe)
+            excess / span
         }
     }
 
@@ -80,12 +252,23 @@ pub mod risk {
             .map(|c| c.bands.weight * c.value)
             .sum()
     }
+
+    /// 1-sigma uncertainty of V_t, treating coordinate errors as independent:
+    /// sqrt(sum_j (w_j * sigma_j)^2).
+    pub fn compute_residual_sigma(coords: &[RiskCoord]) -> f64 {
+        coords
+            .iter()
+            .map(|c| (c.bands.weight * c.sigma).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
 }
 
 pub mod hive {
 
     use super::bands::CorridorBands;
-    use super::risk::{compute_residual, to_risk, Residual, RiskCoord};
+    use super::reserves::{ReserveCorridors, Season};
+    use super::risk::{compute_residual, compute_residual_sigma, Residual, RiskCoord};
 
     /// Bee-centered envelope: no human fields; only hive and landscape metrics.
     #[derive(Clone, Debug)]
@@ -102,9 +285,85 @@ pub mod hive {
         pub toxin_index_air: f64,        // e.g., normalized pesticide index
         pub toxin_index_wax: f64,
         pub forage_radius_km: f64,
-        pub eco_band: EcoBand,
+        /// Season of the reading; selects reserve minimums and consumption.
+        pub season: Season,
+        /// 1-sigma uncertainty of each metric above, in the same raw units.
+        pub sigma: EnvelopeSigma,
+    }
+
+    /// Measurement uncertainty (1-sigma, raw units) for each envelope metric.
+    ///
+    /// Defaults describe a typically instrumented hive; a hive with fewer or
+    /// cheaper sensors should report larger values so that decisions on the
+    /// upper confidence bound treat it more conservatively.
+    #[derive(Clone, Debug)]
+    pub struct EnvelopeSigma {
+        pub brood_temp_c: f64,
+        pub hive_temp_c: f64,
+        pub hive_humidity_pct: f64,
+        pub nectar_kg: f64,
+        pub pollen_kg: f64,
+        pub forager_load_pct: f64,
+        pub toxin_index_air: f64,
+        pub toxin_index_wax: f64,
+        pub forage_radius_km: f64,
     }
 
+    impl Default for EnvelopeSigma {
+        fn default() -> Self {
+            Self {
+                brood_temp_c: 0.3,
+                hive_temp_c: 0.5,
+                hive_humidity_pct: 3.0,
+                nectar_kg: 0.5,
+                pollen_kg: 0.2,
+                forager_load_pct: 0.05,
+                toxin_index_air: 0.05,
+                toxin_index_wax: 0.05,
+                forage_radius_km: 0.2,
+            }
+        }
+    }
+
+    /// How `evaluate_hive_with` turns coordinates into derate/stop decisions.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct DecisionPolicy {
+        /// Decide on `r_x + k * sigma` instead of `r_x`; 0 (the default) uses
+        /// the point estimate.
+        pub confidence_k: f64,
+    }
+
+    impl DecisionPolicy {
+        /// Act on the ~95 % one-sided upper confidence bound.
+        pub fn upper_confidence() -> Self {
+            Self { confidence_k: 1.645 }
+        }
+    }
+
+    impl HiveEnvelope {
+        /// Band of this envelope under `corridors`, derived from its residual.
+        pub fn eco_band(&self, corridors: &HiveCorridors) -> EcoBand {
+            evaluate_hive(self, corridors).eco_band()
+        }
+
+        /// `(measured, sigma)` for each corridor, in
+        /// [`HiveCorridors::slots`] order.
+        fn readings(&self) -> [(f64, f64); 7] {
+            let s = &self.sigma;
+            [
+                (self.hive_temp_c, s.hive_temp_c),
+                (self.brood_temp_c, s.brood_temp_c),
+                (self.hive_humidity_pct, s.hive_humidity_pct),
+                (self.toxin_index_air, s.toxin_index_air),
+                (self.toxin_index_wax, s.toxin_index_wax),
+                (self.forage_radius_km, s.forage_radius_km),
+                (self.forager_load_pct, s.forager_load_pct),
+            ]
+        }
+    }
+
+    /// Hive state band: Safe inside every gold band, Warning beyond a gold
+    /// edge, Critical at a hard edge.
     #[derive(Clone, Debug, Copy, PartialEq, Eq)]
     pub enum EcoBand {
         Safe,
@@ -121,10 +380,20 @@ pub mod hive {
         pub delta_irrigation_m3_per_day: f64,
         pub delta_light_pollution_lm: f64,
         pub delta_foraging_corridor_km: f64,
-        pub rationale: &'static str,
+        pub rationale: String,
     }
 
     /// Corridors required for bee safety (temperature, toxins, forage, etc.).
+    ///
+    /// Load shipped corridors with [`HiveCorridors::from_toml_str`] or
+    /// [`HiveCorridors::from_json_str`], and check hand-built ones with
+    /// [`validate_corridors`](super::corridors::validate_corridors) before
+    /// admitting a hive: no corridor, no build.
+    ///
+    /// Each band carries its own [`BandDirection`](super::bands::BandDirection):
+    /// temperatures are normally two-sided (overheating and chilling), humidity
+    /// two-sided, toxins and forager load higher-is-worse, and forage radius
+    /// lower-is-worse.
     #[derive(Clone, Debug)]
     pub struct HiveCorridors {
         pub temp_bands: CorridorBands,
@@ -134,9 +403,35 @@ pub mod hive {
         pub toxin_wax_bands: CorridorBands,
         pub forage_radius_bands: CorridorBands,
         pub forager_load_bands: CorridorBands,
+        /// Food-reserve corridors. Without them a starving colony can still
+        /// score V_t = 0; corridor files do not carry them, so attach them
+        /// with [`HiveCorridors::with_reserves`].
+        pub reserves: Option<ReserveCorridors>,
+    }
+
+    impl HiveCorridors {
+        pub fn with_reserves(mut self, reserves: ReserveCorridors) -> Self {
+            self.reserves = Some(reserves);
+            self
+        }
+
+        /// Every band with its slot name (the key used in corridor files).
+        pub fn slots(&self) -> [(&'static str, &CorridorBands); 7] {
+            [
+                ("temp", &self.temp_bands),
+                ("brood_temp", &self.brood_temp_bands),
+                ("humidity", &self.humidity_bands),
+                ("toxin_air", &self.toxin_air_bands),
+                ("toxin_wax", &self.toxin_wax_bands),
+                ("forage_radius", &self.forage_radius_bands),
+                ("forager_load", &self.forager_load_bands),
+            ]
+        }
     }
 
     /// Policy thresholds summarized as KER for the hive corridor state.
+    ///
+    /// Derive it from data with [`compute_ker`] rather than asserting values.
     #[derive(Clone, Debug)]
     pub struct HiveKER {
         pub knowledge_factor: f64,   // 0–1 coverage of critical bee variables
@@ -144,87 +439,143 @@ pub mod hive {
         pub risk_of_harm: f64,       // 0–1 residual corridor penetration
     }
 
-    /// No-corridor, no-build invariant: all mandatory corridors must be present
-    /// and well-formed before any hive can be admitted to the governed stack.
-    pub fn corridor_present(c: &HiveCorridors) -> bool {
-        let bands = [
-            &c.temp_bands,
-            &c.brood_temp_bands,
-            &c.humidity_bands,
-            &c.toxin_air_bands,
-            &c.toxin_wax_bands,
-            &c.forage_radius_bands,
-            &c.forager_load_bands,
-        ];
+    /// An adjustment that was applied, with the residual before and after.
+    #[derive(Clone, Debug)]
+    pub struct AdjustmentRecord {
+        pub adjustment: HiveSystemAdjustment,
+        pub vt_before: f64,
+        pub vt_after: f64,
+    }
 
-        bands.iter().all(|b| {
-            (!b.mandatory) || (b.hard > 0.0 && b.gold <= b.hard && b.safe <= b.gold)
-        })
+    /// KER for a hive from its envelope, corridors and adjustment history.
+    pub fn compute_ker(
+        env: &HiveEnvelope,
+        corridors: &HiveCorridors,
+        history: &[AdjustmentRecord],
+        policy: DecisionPolicy,
+    ) -> HiveKER {
+        let residual = evaluate_hive_with(env, corridors, policy);
+        HiveKER {
+            knowledge_factor: knowledge_factor(env, corridors),
+            eco_impact: eco_impact(history),
+            risk_of_harm: risk_of_harm(&residual, policy),
+        }
     }
 
-    /// Compute hive residual and band (Safe / Warning / Critical).
+    /// Weighted share of corridors that are both measured and resolved.
+    ///
+    /// A corridor counts only if its reading and sigma are finite; it is then
+    /// credited `1 - sigma / w`, where `w` is the raw width of its narrowest
+    /// safe→gold interval. A sigma as wide as that interval cannot tell safe
+    /// from derate and earns nothing.
+    /// An unmeasured corridor is not merely uncredited here: its coordinate
+    /// sits at the hard edge, so [`evaluate_hive_with`] stops the hive.
+    pub fn knowledge_factor(env: &HiveEnvelope, corridors: &HiveCorridors) -> f64 {
+        let mut known = 0.0;
+        let mut total = 0.0;
+        for ((_, bands), (measured, sigma)) in corridors.slots().into_iter().zip(env.readings()) {
+            total += bands.weight;
+            if !measured.is_finite() || !sigma.is_finite() {
+                continue;
+            }
+            let width = [bands.upper_edges(), bands.lower_edges()]
+                .into_iter()
+                .flatten()
+                .map(|e| (e.gold - e.safe).abs())
+                .fold(f64::INFINITY, f64::min);
+            if width > 0.0 {
+                known += bands.weight * (1.0 - sigma.abs() / width).max(0.0);
+            }
+        }
+        if total > 0.0 {
+            (known / total).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Deepest corridor penetration, on each coordinate's upper bound under
+    /// `policy`: 0 inside every safe interior, 1 at a hard edge.
+    pub fn risk_of_harm(residual: &Residual, policy: DecisionPolicy) -> f64 {
+        residual
+            .coords
+            .iter()
+            .map(|c| c.upper_bound(policy.confidence_k))
+            .fold(0.0, f64::max)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Mean relative V_t reduction achieved by past adjustments, floored at 0.
+    ///
+    /// An adjustment that raised V_t from 0 counts as -1; an empty history has
+    /// demonstrated no benefit and scores 0.
+    pub fn eco_impact(history: &[AdjustmentRecord]) -> f64 {
+        if history.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = history
+            .iter()
+            .map(|h| {
+                if h.vt_before > 0.0 {
+                    ((h.vt_before - h.vt_after) / h.vt_before).clamp(-1.0, 1.0)
+                } else if h.vt_after > 0.0 {
+                    -1.0
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        (total / history.len() as f64).clamp(0.0, 1.0)
+    }
+
+    /// Compute hive residual and band (Safe / Warning / Critical), deciding on
+    /// point estimates. See [`evaluate_hive_with`].
     pub fn evaluate_hive(env: &HiveEnvelope, corridors: &HiveCorridors) -> Residual {
-        let coords = vec![
-            RiskCoord {
-                var_id: corridors.temp_bands.var_id,
-                sigma: 0.05,
-                value: to_risk(env.hive_temp_c, &corridors.temp_bands),
-                bands: corridors.temp_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.brood_temp_bands.var_id,
-                sigma: 0.05,
-                value: to_risk(env.brood_temp_c, &corridors.brood_temp_bands),
-                bands: corridors.brood_temp_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.humidity_bands.var_id,
-                sigma: 0.05,
-                value: to_risk(env.hive_humidity_pct, &corridors.humidity_bands),
-                bands: corridors.humidity_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.toxin_air_bands.var_id,
-                sigma: 0.10,
-                value: to_risk(env.toxin_index_air, &corridors.toxin_air_bands),
-                bands: corridors.toxin_air_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.toxin_wax_bands.var_id,
-                sigma: 0.10,
-                value: to_risk(env.toxin_index_wax, &corridors.toxin_wax_bands),
-                bands: corridors.toxin_wax_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.forage_radius_bands.var_id,
-                sigma: 0.05,
-                value: to_risk(env.forage_radius_km, &corridors.forage_radius_bands),
-                bands: corridors.forage_radius_bands.clone(),
-            },
-            RiskCoord {
-                var_id: corridors.forager_load_bands.var_id,
-                sigma: 0.05,
-                value: to_risk(env.forager_load_pct, &corridors.forager_load_bands),
-                bands: corridors.forager_load_bands.clone(),
-            },
-        ];
+        evaluate_hive_with(env, corridors, DecisionPolicy::default())
+    }
+
+    /// Compute hive residual with sigma propagated from `env.sigma`, deciding
+    /// derate/stop on each coordinate's upper bound under `policy`.
+    /// A missing (non-finite) reading counts as a hard-edge violation.
+    pub fn evaluate_hive_with(
+        env: &HiveEnvelope,
+        corridors: &HiveCorridors,
+        policy: DecisionPolicy,
+    ) -> Residual {
+        let mut coords: Vec<RiskCoord> = corridors
+            .slots()
+            .into_iter()
+            .zip(env.readings())
+            .map(|((_, bands), (measured, sigma))| RiskCoord::new(measured, sigma, bands))
+            .collect();
+        if let Some(reserves) = &corridors.reserves {
+            coords.extend(reserves.risk_coords(env));
+        }
 
         let vt = compute_residual(&coords);
+        let vt_sigma = compute_residual_sigma(&coords);
 
         let mut derate = false;
         let mut stop = false;
 
         for c in &coords {
-            if c.value >= 1.0 {
+            let r = c.upper_bound(policy.confidence_k);
+            if r >= 1.0 {
                 // Hard violation: hive in critical corridor → stop.
                 stop = true;
-            } else if c.value > c.bands.gold {
+            } else if r > c.r_gold {
                 // Between gold and hard: derate.
                 derate = true;
             }
         }
 
-        Residual { vt, coords, derate, stop }
+        Residual {
+            vt,
+            vt_sigma,
+            coords,
+            derate,
+            stop,
+        }
     }
 
     /// Runtime invariant: no adjustment may increase bee risk or violate hard limits.
@@ -248,8 +599,9 @@ pub mod hive {
         decision
     }
 
-    /// Example policy: no action may increase pesticide exposure, raise hive
-    /// temperature above safe band, or reduce forage radius below corridor.
+    /// Example policy: no action may increase pesticide exposure, push hive
+    /// temperature out of or further from its safe band, or reduce forage
+    /// radius below corridor.
     pub fn policy_allows_adjustment(
         envelope_before: &HiveEnvelope,
         envelope_after: &HiveEnvelope,
@@ -263,14 +615,15 @@ pub mod hive {
             return false;
         }
 
-        // Hive temperature must not move from <= safe band to > safe band.
+        // Hive temperature must not leave the safe band (r = 0 in risk space),
+        // nor move deeper into a tail it is already in.
         let r_before_temp = super::risk::to_risk(envelope_before.hive_temp_c, &corridors.temp_bands);
         let r_after_temp  = super::risk::to_risk(envelope_after.hive_temp_c, &corridors.temp_bands);
-        if r_before_temp <= corridors.temp_bands.safe && r_after_temp > corridors.temp_bands.safe {
+        if r_after_temp > r_before_temp {
             return false;
         }
 
-        // Forage radius must not shrink below safe band.
+        // Forage radius must not shrink toward its lower hard edge.
         let r_before_forage = super::risk::to_risk(
             envelope_before.forage_radius_km,
             &corridors.forage_radius_bands,
@@ -286,3 +639,1685 @@ pub mod hive {
         true
     }
 }
+
+pub mod corridors {
+    //! Declarative corridor files and corridor validation.
+    //!
+    //! A corridor file has one table per [`HiveCorridors`] slot; each band
+    //! gives an `upper` and/or `lower` tail:
+    //!
+    //! ```toml
+    //! [humidity]
+    //! var_id = "hive_humidity"
+    //! units = "%"
+    //! upper = { safe = 70.0, gold = 80.0, hard = 90.0 }
+    //! lower = { safe = 50.0, gold = 40.0, hard = 30.0 }
+    //! weight = 0.5
+    //! lyap_channel = 3
+    //! ```
+
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    use serde::Deserialize;
+
+    use super::bands::{BandDirection, BandEdges, CorridorBands};
+    use super::hive::HiveCorridors;
+
+    /// Units a corridor may be expressed in.
+    pub const KNOWN_UNITS: &[&str] = &[
+        "dimensionless",
+        "fraction",
+        "index",
+        "C",
+        "%",
+        "km",
+        "kg",
+        "days",
+        "ug/m3",
+        "ppb",
+    ];
+
+    /// Slot names in [`HiveCorridors::slots`] order.
+    const SLOTS: [&str; 7] = [
+        "temp",
+        "brood_temp",
+        "humidity",
+        "toxin_air",
+        "toxin_wax",
+        "forage_radius",
+        "forager_load",
+    ];
+
+    /// One band as written in a corridor file.
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct CorridorBandsSpec {
+        pub var_id: String,
+        pub units: String,
+        /// Higher-is-worse tail: `safe <= gold <= hard`.
+        #[serde(default)]
+        pub upper: Option<BandEdges>,
+        /// Lower-is-worse tail: `hard <= gold <= safe`.
+        #[serde(default)]
+        pub lower: Option<BandEdges>,
+        pub weight: f64,
+        #[serde(default)]
+        pub lyap_channel: u32,
+        #[serde(default = "default_mandatory")]
+        pub mandatory: bool,
+    }
+
+    fn default_mandatory() -> bool {
+        true
+    }
+
+    impl CorridorBandsSpec {
+        fn into_bands(self) -> Option<CorridorBands> {
+            let (edges, direction) = match (self.upper, self.lower) {
+                (Some(upper), None) => (upper, BandDirection::HigherIsWorse),
+                (None, Some(lower)) => (lower, BandDirection::LowerIsWorse),
+                (Some(upper), Some(lower)) => (upper, BandDirection::TwoSided { lower }),
+                (None, None) => return None,
+            };
+            Some(CorridorBands {
+                var_id: self.var_id,
+                units: self.units,
+                safe: edges.safe,
+                gold: edges.gold,
+                hard: edges.hard,
+                direction,
+                weight: self.weight,
+                lyap_channel: self.lyap_channel,
+                mandatory: self.mandatory,
+            })
+        }
+    }
+
+    /// Which tail of a band a diagnostic refers to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Tail {
+        Upper,
+        Lower,
+    }
+
+    /// What is wrong with a corridor.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum DiagnosticKind {
+        /// A slot every hive needs is absent from the file.
+        MissingMandatoryCorridor,
+        /// Neither an `upper` nor a `lower` tail was given.
+        NoTails,
+        /// The safe edge lies beyond the gold edge.
+        SafeBeyondGold { tail: Tail, safe: f64, gold: f64 },
+        /// The gold edge lies beyond the hard edge.
+        GoldBeyondHard { tail: Tail, gold: f64, hard: f64 },
+        /// The lower tail's safe edge is above the upper tail's safe edge.
+        TailsOverlap { lower_safe: f64, upper_safe: f64 },
+        NonFiniteEdge,
+        NonPositiveWeight { weight: f64 },
+        UnknownUnits { units: String },
+    }
+
+    /// A named, actionable problem with one corridor slot.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CorridorDiagnostic {
+        pub slot: String,
+        /// `None` when the slot is missing altogether.
+        pub var_id: Option<String>,
+        pub kind: DiagnosticKind,
+    }
+
+    impl fmt::Display for CorridorDiagnostic {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.var_id {
+                Some(id) => write!(f, "{} ({id}): ", self.slot)?,
+                None => write!(f, "{}: ", self.slot)?,
+            }
+            match &self.kind {
+                DiagnosticKind::MissingMandatoryCorridor => {
+                    write!(f, "mandatory corridor is missing")
+                }
+                DiagnosticKind::NoTails => write!(f, "neither an upper nor a lower tail is given"),
+                DiagnosticKind::SafeBeyondGold { tail, safe, gold } => {
+                    write!(f, "{tail:?} tail: safe {safe} lies beyond gold {gold}")
+                }
+                DiagnosticKind::GoldBeyondHard { tail, gold, hard } => {
+                    write!(f, "{tail:?} tail: gold {gold} lies beyond hard {hard}")
+                }
+                DiagnosticKind::TailsOverlap {
+                    lower_safe,
+                    upper_safe,
+                } => write!(
+                    f,
+                    "lower safe edge {lower_safe} is above upper safe edge {upper_safe}"
+                ),
+                DiagnosticKind::NonFiniteEdge => write!(f, "band edges must be finite"),
+                DiagnosticKind::NonPositiveWeight { weight } => {
+                    write!(f, "weight {weight} must be positive")
+                }
+                DiagnosticKind::UnknownUnits { units } => write!(
+                    f,
+                    "unknown units {units:?}; expected one of {}",
+                    KNOWN_UNITS.join(", ")
+                ),
+            }
+        }
+    }
+
+    /// Why a corridor file could not be loaded.
+    #[derive(Debug)]
+    pub enum CorridorLoadError {
+        /// The file is not valid TOML/JSON or does not match the schema.
+        Parse(String),
+        /// The file parsed but the corridors are not admissible.
+        Invalid(Vec<CorridorDiagnostic>),
+    }
+
+    impl fmt::Display for CorridorLoadError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                CorridorLoadError::Parse(msg) => write!(f, "corridor file parse error: {msg}"),
+                CorridorLoadError::Invalid(diagnostics) => {
+                    write!(f, "{} corridor problem(s)", diagnostics.len())?;
+                    for d in diagnostics {
+                        write!(f, "\n  - {d}")?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for CorridorLoadError {}
+
+    /// Check every mandatory band of `corridors`. An empty list means the hive
+    /// may be admitted to the governed stack.
+    ///
+    /// Bands with `mandatory = false` are advisory and never block admission;
+    /// check them with [`validate_bands`] directly.
+    pub fn validate_corridors(corridors: &HiveCorridors) -> Vec<CorridorDiagnostic> {
+        let mut diagnostics: Vec<_> = corridors
+            .slots()
+            .into_iter()
+            .filter(|(_, bands)| bands.mandatory)
+            .flat_map(|(slot, bands)| validate_bands(slot, bands))
+            .collect();
+        if let Some(reserves) = &corridors.reserves {
+            for (slot, bands) in reserves.slots() {
+                if bands.mandatory {
+                    diagnostics.extend(validate_bands(&slot, bands));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Check one band in isolation.
+    pub fn validate_bands(slot: &str, bands: &CorridorBands) -> Vec<CorridorDiagnostic> {
+        let mut kinds = Vec::new();
+
+        let tails = [
+            (Tail::Upper, bands.upper_edges()),
+            (Tail::Lower, bands.lower_edges()),
+        ];
+        for (tail, edges) in tails {
+            let Some(e) = edges else { continue };
+            if !(e.safe.is_finite() && e.gold.is_finite() && e.hard.is_finite()) {
+                kinds.push(DiagnosticKind::NonFiniteEdge);
+                continue;
+            }
+            // Distance from the safe interior grows upward on the upper tail
+            // and downward on the lower tail.
+            let beyond = |a: f64, b: f64| match tail {
+                Tail::Upper => a > b,
+                Tail::Lower => a < b,
+            };
+            if beyond(e.safe, e.gold) {
+                kinds.push(DiagnosticKind::SafeBeyondGold {
+                    tail,
+                    safe: e.safe,
+                    gold: e.gold,
+                });
+            }
+            if beyond(e.gold, e.hard) {
+                kinds.push(DiagnosticKind::GoldBeyondHard {
+                    tail,
+                    gold: e.gold,
+                    hard: e.hard,
+                });
+            }
+        }
+        if let (Some(lo), Some(hi)) = (bands.lower_edges(), bands.upper_edges()) {
+            if lo.safe > hi.safe {
+                kinds.push(DiagnosticKind::TailsOverlap {
+                    lower_safe: lo.safe,
+                    upper_safe: hi.safe,
+                });
+            }
+        }
+        if bands.weight.is_nan() || bands.weight <= 0.0 {
+            kinds.push(DiagnosticKind::NonPositiveWeight {
+                weight: bands.weight,
+            });
+        }
+        if !KNOWN_UNITS.contains(&bands.units.as_str()) {
+            kinds.push(DiagnosticKind::UnknownUnits {
+                units: bands.units.clone(),
+            });
+        }
+
+        kinds
+            .into_iter()
+            .map(|kind| CorridorDiagnostic {
+                slot: slot.to_string(),
+                var_id: Some(bands.var_id.clone()),
+                kind,
+            })
+            .collect()
+    }
+
+    impl HiveCorridors {
+        /// Load corridors from TOML; see the [module docs](self) for the format.
+        pub fn from_toml_str(text: &str) -> Result<Self, CorridorLoadError> {
+            let specs: BTreeMap<String, CorridorBandsSpec> =
+                toml::from_str(text).map_err(|e| CorridorLoadError::Parse(e.to_string()))?;
+            Self::from_specs(specs)
+        }
+
+        /// Load corridors from JSON with the same layout as the TOML format.
+        pub fn from_json_str(text: &str) -> Result<Self, CorridorLoadError> {
+            let specs: BTreeMap<String, CorridorBandsSpec> = serde_json::from_str(text)
+                .map_err(|e| CorridorLoadError::Parse(e.to_string()))?;
+            Self::from_specs(specs)
+        }
+
+        /// Build corridors from parsed band specs, collecting every problem
+        /// (missing slots, unknown slots, malformed mandatory bands) before
+        /// failing. As in [`validate_corridors`], an advisory band only needs
+        /// a tail.
+        pub fn from_specs(
+            mut specs: BTreeMap<String, CorridorBandsSpec>,
+        ) -> Result<Self, CorridorLoadError> {
+            let unknown: Vec<_> = specs
+                .keys()
+                .filter(|k| !SLOTS.contains(&k.as_str()))
+                .cloned()
+                .collect();
+            if !unknown.is_empty() {
+                return Err(CorridorLoadError::Parse(format!(
+                    "unknown corridor slot(s) {}; expected {}",
+                    unknown.join(", "),
+                    SLOTS.join(", ")
+                )));
+            }
+
+            let mut diagnostics = Vec::new();
+            let mut bands: Vec<Option<CorridorBands>> = Vec::with_capacity(SLOTS.len());
+            for slot in SLOTS {
+                let Some(spec) = specs.remove(slot) else {
+                    diagnostics.push(CorridorDiagnostic {
+                        slot: slot.to_string(),
+                        var_id: None,
+                        kind: DiagnosticKind::MissingMandatoryCorridor,
+                    });
+                    bands.push(None);
+                    continue;
+                };
+                let var_id = spec.var_id.clone();
+                match spec.into_bands() {
+                    Some(b) => {
+                        if b.mandatory {
+                            diagnostics.extend(validate_bands(slot, &b));
+                        }
+                        bands.push(Some(b));
+                    }
+                    None => {
+                        diagnostics.push(CorridorDiagnostic {
+                            slot: slot.to_string(),
+                            var_id: Some(var_id),
+                            kind: DiagnosticKind::NoTails,
+                        });
+                        bands.push(None);
+                    }
+                }
+            }
+            if !diagnostics.is_empty() {
+                return Err(CorridorLoadError::Invalid(diagnostics));
+            }
+
+            let mut bands = bands.into_iter().map(|b| b.expect("no diagnostics"));
+            let mut next = || bands.next().expect("one band per slot");
+            Ok(HiveCorridors {
+                temp_bands: next(),
+                brood_temp_bands: next(),
+                humidity_bands: next(),
+                toxin_air_bands: next(),
+                toxin_wax_bands: next(),
+                forage_radius_bands: next(),
+                forager_load_bands: next(),
+                reserves: None,
+            })
+        }
+    }
+}
+
+pub mod reserves {
+    //! Food-reserve corridors: nectar and pollen stores against
+    //! season-dependent minimums, and days of reserve at the current draw.
+
+    use super::bands::CorridorBands;
+    use super::hive::HiveEnvelope;
+    use super::risk::RiskCoord;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum Season {
+        Spring,
+        #[default]
+        Summer,
+        Autumn,
+        Winter,
+    }
+
+    /// One value per season.
+    #[derive(Clone, Debug)]
+    pub struct Seasonal<T> {
+        pub spring: T,
+        pub summer: T,
+        pub autumn: T,
+        pub winter: T,
+    }
+
+    impl<T> Seasonal<T> {
+        pub fn get(&self, season: Season) -> &T {
+            match season {
+                Season::Spring => &self.spring,
+                Season::Summer => &self.summer,
+                Season::Autumn => &self.autumn,
+                Season::Winter => &self.winter,
+            }
+        }
+    }
+
+    /// Colony draw on its stores.
+    #[derive(Clone, Debug)]
+    pub struct ReserveConsumption {
+        pub nectar_kg_per_day: Seasonal<f64>,
+        pub pollen_kg_per_day: Seasonal<f64>,
+        /// Extra draw per unit forager load: `rate * (1 + load_gain * load)`.
+        pub load_gain: f64,
+    }
+
+    /// Reserve corridors; all bands are expected to be lower-is-worse.
+    #[derive(Clone, Debug)]
+    pub struct ReserveCorridors {
+        /// Minimum nectar (honey) store, kg, per season.
+        pub nectar: Seasonal<CorridorBands>,
+        /// Minimum pollen (bee bread) store, kg, per season.
+        pub pollen: Seasonal<CorridorBands>,
+        /// Minimum days of reserve at the current draw.
+        pub days_of_reserve: CorridorBands,
+        pub consumption: ReserveConsumption,
+    }
+
+    impl ReserveCorridors {
+        /// Days until the first store runs out at the current season's draw,
+        /// with its 1-sigma uncertainty. Stores with no draw last forever,
+        /// reported as `f64::MAX` days so the coordinate stays finite (r = 0).
+        pub fn days_of_reserve(&self, env: &HiveEnvelope) -> (f64, f64) {
+            let c = &self.consumption;
+            let load = 1.0 + c.load_gain * env.forager_load_pct.max(0.0);
+            let stores = [
+                (env.nectar_kg, env.sigma.nectar_kg, *c.nectar_kg_per_day.get(env.season)),
+                (env.pollen_kg, env.sigma.pollen_kg, *c.pollen_kg_per_day.get(env.season)),
+            ];
+            stores
+                .into_iter()
+                .filter(|&(_, _, rate)| rate > 0.0)
+                .map(|(kg, sigma, rate)| {
+                    let draw = rate * load;
+                    (kg.max(0.0) / draw, sigma.abs() / draw)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .unwrap_or((f64::MAX, 0.0))
+        }
+
+        /// Starvation risk coordinates: nectar, pollen and days of reserve.
+        pub fn risk_coords(&self, env: &HiveEnvelope) -> [RiskCoord; 3] {
+            let (days, days_sigma) = self.days_of_reserve(env);
+            [
+                RiskCoord::new(env.nectar_kg, env.sigma.nectar_kg, self.nectar.get(env.season)),
+                RiskCoord::new(env.pollen_kg, env.sigma.pollen_kg, self.pollen.get(env.season)),
+                RiskCoord::new(days, days_sigma, &self.days_of_reserve),
+            ]
+        }
+
+        /// Every band with a slot name, for diagnostics.
+        pub fn slots(&self) -> Vec<(String, &CorridorBands)> {
+            let seasons = [
+                ("spring", Season::Spring),
+                ("summer", Season::Summer),
+                ("autumn", Season::Autumn),
+                ("winter", Season::Winter),
+            ];
+            let mut slots = Vec::new();
+            for (store, bands) in [("nectar", &self.nectar), ("pollen", &self.pollen)] {
+                for (name, season) in seasons {
+                    slots.push((format!("reserves.{store}.{name}"), bands.get(season)));
+                }
+            }
+            slots.push(("reserves.days".to_string(), &self.days_of_reserve));
+            slots
+        }
+    }
+}
+
+pub mod planner {
+    //! Search for landscape adjustments that lower a hive's residual.
+
+    use super::hive::{
+        evaluate_hive_with, policy_allows_adjustment, safe_step, DecisionPolicy, HiveCorridors,
+        HiveEnvelope, HiveSystemAdjustment,
+    };
+    use super::risk::Residual;
+
+    /// Predicts the envelope that would follow an adjustment.
+    pub trait ResponseModel {
+        fn predict(&self, envelope: &HiveEnvelope, adjustment: &HiveSystemAdjustment)
+            -> HiveEnvelope;
+    }
+
+    /// Change in each envelope metric per unit of one adjustment delta.
+    #[derive(Clone, Debug, Default)]
+    pub struct MetricResponse {
+        pub hive_temp_c: f64,
+        pub brood_temp_c: f64,
+        pub hive_humidity_pct: f64,
+        pub nectar_kg: f64,
+        pub pollen_kg: f64,
+        pub forager_load_pct: f64,
+        pub toxin_index_air: f64,
+        pub toxin_index_wax: f64,
+        pub forage_radius_km: f64,
+    }
+
+    /// Linear response: each metric moves by the sum of coefficient × delta.
+    ///
+    /// Coefficients are site-specific and must be calibrated from landscape
+    /// trials; all default to 0 (no predicted effect).
+    #[derive(Clone, Debug, Default)]
+    pub struct LinearResponseModel {
+        pub per_wildflower_m2: MetricResponse,
+        pub per_pesticide_pct: MetricResponse,
+        pub per_irrigation_m3_per_day: MetricResponse,
+        pub per_light_lm: MetricResponse,
+        pub per_corridor_km: MetricResponse,
+    }
+
+    impl ResponseModel for LinearResponseModel {
+        fn predict(
+            &self,
+            envelope: &HiveEnvelope,
+            adjustment: &HiveSystemAdjustment,
+        ) -> HiveEnvelope {
+            let terms = [
+                (&self.per_wildflower_m2, adjustment.delta_wildflower_area_m2),
+                (&self.per_pesticide_pct, adjustment.delta_pesticide_use_pct),
+                (&self.per_irrigation_m3_per_day, adjustment.delta_irrigation_m3_per_day),
+                (&self.per_light_lm, adjustment.delta_light_pollution_lm),
+                (&self.per_corridor_km, adjustment.delta_foraging_corridor_km),
+            ];
+            let mut next = envelope.clone();
+            for (r, d) in terms {
+                next.hive_temp_c += r.hive_temp_c * d;
+                next.brood_temp_c += r.brood_temp_c * d;
+                next.hive_humidity_pct += r.hive_humidity_pct * d;
+                next.nectar_kg += r.nectar_kg * d;
+                next.pollen_kg += r.pollen_kg * d;
+                next.forager_load_pct += r.forager_load_pct * d;
+                next.toxin_index_air += r.toxin_index_air * d;
+                next.toxin_index_wax += r.toxin_index_wax * d;
+                next.forage_radius_km += r.forage_radius_km * d;
+            }
+            // Keep predictions physically meaningful.
+            next.hive_humidity_pct = next.hive_humidity_pct.clamp(0.0, 100.0);
+            next.nectar_kg = next.nectar_kg.max(0.0);
+            next.pollen_kg = next.pollen_kg.max(0.0);
+            next.forager_load_pct = next.forager_load_pct.max(0.0);
+            next.toxin_index_air = next.toxin_index_air.max(0.0);
+            next.toxin_index_wax = next.toxin_index_wax.max(0.0);
+            next.forage_radius_km = next.forage_radius_km.max(0.0);
+            next
+        }
+    }
+
+    /// Candidate values searched for each delta (full grid), and how
+    /// residuals are decided.
+    #[derive(Clone, Debug)]
+    pub struct PlannerConfig {
+        pub wildflower_area_m2: Vec<f64>,
+        pub pesticide_use_pct: Vec<f64>,
+        pub irrigation_m3_per_day: Vec<f64>,
+        pub light_pollution_lm: Vec<f64>,
+        pub foraging_corridor_km: Vec<f64>,
+        /// Number of ranked alternatives to return.
+        pub max_alternatives: usize,
+        pub policy: DecisionPolicy,
+    }
+
+    impl Default for PlannerConfig {
+        fn default() -> Self {
+            Self {
+                wildflower_area_m2: vec![0.0, 250.0, 500.0, 1000.0],
+                pesticide_use_pct: vec![0.0, -10.0, -25.0, -50.0],
+                irrigation_m3_per_day: vec![0.0, 0.5, 1.0],
+                light_pollution_lm: vec![0.0, -500.0, -1000.0],
+                foraging_corridor_km: vec![0.0, 0.5, 1.0],
+                max_alternatives: 5,
+                policy: DecisionPolicy::default(),
+            }
+        }
+    }
+
+    /// One admissible adjustment with its predicted outcome.
+    #[derive(Clone, Debug)]
+    pub struct PlannedAdjustment {
+        pub adjustment: HiveSystemAdjustment,
+        pub predicted: HiveEnvelope,
+        pub vt_before: f64,
+        pub vt_predicted: f64,
+        /// Decision for the step, after `safe_step`.
+        pub residual: Residual,
+    }
+
+    /// Search the candidate grid for adjustments that pass
+    /// `policy_allows_adjustment` and a non-stopping `safe_step`, ranked by
+    /// predicted V_t (then by smallest intervention). The first entry is the
+    /// recommended plan; an empty list means no candidate is admissible.
+    pub fn plan_adjustments(
+        envelope: &HiveEnvelope,
+        corridors: &HiveCorridors,
+        model: &impl ResponseModel,
+        config: &PlannerConfig,
+    ) -> Vec<PlannedAdjustment> {
+        let before = evaluate_hive_with(envelope, corridors, config.policy);
+
+        let mut plans = Vec::new();
+        for &wildflower in &config.wildflower_area_m2 {
+            for &pesticide in &config.pesticide_use_pct {
+                for &irrigation in &config.irrigation_m3_per_day {
+                    for &light in &config.light_pollution_lm {
+                        for &corridor in &config.foraging_corridor_km {
+                            let mut adjustment = HiveSystemAdjustment {
+                                hive_id: envelope.hive_id.clone(),
+                                delta_wildflower_area_m2: wildflower,
+                                delta_pesticide_use_pct: pesticide,
+                                delta_irrigation_m3_per_day: irrigation,
+                                delta_light_pollution_lm: light,
+                                delta_foraging_corridor_km: corridor,
+                                rationale: String::new(),
+                            };
+                            let predicted = model.predict(envelope, &adjustment);
+                            if !policy_allows_adjustment(envelope, &predicted, corridors) {
+                                continue;
+                            }
+                            let next = evaluate_hive_with(&predicted, corridors, config.policy);
+                            let residual = safe_step(&before, &next);
+                            if residual.stop {
+                                continue;
+                            }
+                            adjustment.rationale = rationale(&adjustment, &before, &residual);
+                            plans.push(PlannedAdjustment {
+                                adjustment,
+                                predicted,
+                                vt_before: before.vt,
+                                vt_predicted: residual.vt,
+                                residual,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        plans.sort_by(|a, b| {
+            a.vt_predicted
+                .total_cmp(&b.vt_predicted)
+                .then_with(|| {
+                    intervention_size(&a.adjustment).total_cmp(&intervention_size(&b.adjustment))
+                })
+        });
+        plans.truncate(config.max_alternatives);
+        plans
+    }
+
+    /// Scale-free size of an adjustment, for preferring the lightest touch.
+    fn intervention_size(a: &HiveSystemAdjustment) -> f64 {
+        a.delta_wildflower_area_m2.abs() / 1000.0
+            + a.delta_pesticide_use_pct.abs() / 100.0
+            + a.delta_irrigation_m3_per_day.abs()
+            + a.delta_light_pollution_lm.abs() / 1000.0
+            + a.delta_foraging_corridor_km.abs()
+    }
+
+    fn rationale(a: &HiveSystemAdjustment, before: &Residual, after: &Residual) -> String {
+        let actions: Vec<String> = [
+            (a.delta_wildflower_area_m2, "m² wildflowers"),
+            (a.delta_pesticide_use_pct, "% pesticide use"),
+            (a.delta_irrigation_m3_per_day, "m³/day irrigation"),
+            (a.delta_light_pollution_lm, "lm light pollution"),
+            (a.delta_foraging_corridor_km, "km foraging corridor"),
+        ]
+        .iter()
+        .filter(|(d, _)| *d != 0.0)
+        .map(|(d, what)| format!("{d:+} {what}"))
+        .collect();
+
+        // Coordinates whose weighted risk falls the most.
+        let mut gains: Vec<(&str, f64)> = before
+            .coords
+            .iter()
+            .zip(&after.coords)
+            .map(|(b, n)| (b.var_id.as_str(), b.bands.weight * (b.value - n.value)))
+            .filter(|(_, g)| *g > 0.0)
+            .collect();
+        gains.sort_by(|x, y| y.1.total_cmp(&x.1));
+        let drivers: Vec<String> = gains
+            .iter()
+            .take(3)
+            .map(|(id, g)| format!("{id} -{g:.3}"))
+            .collect();
+
+        format!(
+            "{}: V_t {:.3} -> {:.3}{}",
+            if actions.is_empty() {
+                "no change".to_string()
+            } else {
+                actions.join(", ")
+            },
+            before.vt,
+            after.vt,
+            if drivers.is_empty() {
+                String::new()
+            } else {
+                format!(" (largest reductions: {})", drivers.join(", "))
+            }
+        )
+    }
+}
+
+pub mod history {
+    //! Rolling V_t history with noise-tolerant Lyapunov checks.
+
+    use std::collections::VecDeque;
+
+    use super::risk::Residual;
+
+    /// Window length and how much V_t may rise before it counts as a
+    /// Lyapunov violation.
+    #[derive(Clone, Copy, Debug)]
+    pub struct HistoryConfig {
+        /// Residuals kept, oldest dropped first.
+        pub window: usize,
+        /// Absolute ΔV_t always tolerated as measurement noise.
+        pub noise_tolerance: f64,
+        /// Further ΔV_t tolerated per 1-sigma of the step,
+        /// `sqrt(prev.vt_sigma^2 + next.vt_sigma^2)`.
+        pub sigma_k: f64,
+    }
+
+    impl Default for HistoryConfig {
+        fn default() -> Self {
+            Self {
+                window: 96,
+                noise_tolerance: 0.01,
+                sigma_k: 2.0,
+            }
+        }
+    }
+
+    /// A V_t increase beyond tolerance, outside the safe interior.
+    #[derive(Clone, Debug)]
+    pub struct LyapunovViolation {
+        pub t: u64,
+        pub delta_v: f64,
+        pub tolerance: f64,
+        /// Coordinate whose weighted risk rose the most, and by how much.
+        pub driver: String,
+        pub driver_delta: f64,
+    }
+
+    /// Evidence that V_t did not rise beyond tolerance over a window.
+    #[derive(Clone, Debug)]
+    pub struct LyapunovCertificate {
+        pub t_start: u64,
+        pub t_end: u64,
+        pub samples: usize,
+        pub vt_start: f64,
+        pub vt_end: f64,
+        /// Largest step-to-step ΔV_t in the window (≤ its step tolerance).
+        pub max_delta_v: f64,
+        pub noise_tolerance: f64,
+        pub sigma_k: f64,
+        /// Coordinates (var_id) covered by every residual in the window.
+        pub coords: Vec<String>,
+    }
+
+    /// Rolling history of hive residuals.
+    #[derive(Clone, Debug)]
+    pub struct ResidualHistory {
+        config: HistoryConfig,
+        samples: VecDeque<(u64, Residual)>,
+        violations: VecDeque<LyapunovViolation>,
+    }
+
+    impl ResidualHistory {
+        pub fn new(config: HistoryConfig) -> Self {
+            Self {
+                config,
+                samples: VecDeque::new(),
+                violations: VecDeque::new(),
+            }
+        }
+
+        /// Record the residual at time `t` (monotone, caller's units) and
+        /// return the Lyapunov violation it caused, if any.
+        ///
+        /// As in [`safe_step`](super::hive::safe_step), a rise from a state
+        /// entirely inside the safe interior is not a violation.
+        pub fn push(&mut self, t: u64, residual: Residual) -> Option<LyapunovViolation> {
+            let violation = self.samples.back().and_then(|(_, prev)| {
+                let delta_v = residual.vt - prev.vt;
+                let tolerance = self.step_tolerance(prev, &residual);
+                let outside_interior = prev.coords.iter().any(|c| c.value > 0.0);
+                if delta_v <= tolerance || !outside_interior {
+                    return None;
+                }
+                let (driver, driver_delta) = prev
+                    .coords
+                    .iter()
+                    .zip(&residual.coords)
+                    .map(|(p, n)| (n.var_id.clone(), n.bands.weight * (n.value - p.value)))
+                    .max_by(|a, b| a.1.total_cmp(&b.1))
+                    .unwrap_or_default();
+                Some(LyapunovViolation {
+                    t,
+                    delta_v,
+                    tolerance,
+                    driver,
+                    driver_delta,
+                })
+            });
+
+            self.samples.push_back((t, residual));
+            if let Some(v) = &violation {
+                self.violations.push_back(v.clone());
+            }
+            while self.samples.len() > self.config.window.max(1) {
+                self.samples.pop_front();
+            }
+            if let Some(&(t_start, _)) = self.samples.front() {
+                while self.violations.front().is_some_and(|v| v.t < t_start) {
+                    self.violations.pop_front();
+                }
+            }
+            violation
+        }
+
+        /// Latest residual, if any.
+        pub fn latest(&self) -> Option<&Residual> {
+            self.samples.back().map(|(_, r)| r)
+        }
+
+        pub fn len(&self) -> usize {
+            self.samples.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.samples.is_empty()
+        }
+
+        /// Violations whose step still lies in the window.
+        pub fn violations(&self) -> impl Iterator<Item = &LyapunovViolation> {
+            self.violations.iter()
+        }
+
+        /// Certificate of monotone non-increase (up to tolerance) over the
+        /// whole window, or `None` with fewer than two samples or if any step
+        /// rose beyond tolerance — including a departure from the safe
+        /// interior, which `push` does not report as a violation.
+        ///
+        /// The window as a whole is held to a single step's tolerance too, so
+        /// V_t cannot creep up by one tolerated uptick per step.
+        pub fn certificate(&self) -> Option<LyapunovCertificate> {
+            if self.samples.len() < 2 {
+                return None;
+            }
+            let mut max_delta_v = f64::NEG_INFINITY;
+            for ((_, prev), (_, next)) in self.samples.iter().zip(self.samples.iter().skip(1)) {
+                let delta_v = next.vt - prev.vt;
+                if delta_v.is_nan() || delta_v > self.step_tolerance(prev, next) {
+                    return None;
+                }
+                max_delta_v = max_delta_v.max(delta_v);
+            }
+
+            let (t_start, first) = self.samples.front()?;
+            let (t_end, last) = self.samples.back()?;
+            if last.vt - first.vt > self.step_tolerance(first, last) {
+                return None;
+            }
+            let coords = first
+                .coords
+                .iter()
+                .map(|c| c.var_id.clone())
+                .filter(|id| {
+                    self.samples
+                        .iter()
+                        .all(|(_, r)| r.coords.iter().any(|c| &c.var_id == id))
+                })
+                .collect();
+            Some(LyapunovCertificate {
+                t_start: *t_start,
+                t_end: *t_end,
+                samples: self.samples.len(),
+                vt_start: first.vt,
+                vt_end: last.vt,
+                max_delta_v,
+                noise_tolerance: self.config.noise_tolerance,
+                sigma_k: self.config.sigma_k,
+                coords,
+            })
+        }
+
+        fn step_tolerance(&self, prev: &Residual, next: &Residual) -> f64 {
+            self.config.noise_tolerance + self.config.sigma_k * prev.vt_sigma.hypot(next.vt_sigma)
+        }
+    }
+}
+
+pub mod landscape {
+    //! Many hives sharing one landscape: an adjustment reaches every hive
+    //! whose forage radius covers its site, and must be safe for all of them.
+
+    use super::hive::{
+        evaluate_hive_with, policy_allows_adjustment, safe_step, DecisionPolicy, HiveCorridors,
+        HiveEnvelope, HiveSystemAdjustment,
+    };
+    use super::planner::ResponseModel;
+
+    /// Planar position in a local landscape frame, in km.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct Position {
+        pub x_km: f64,
+        pub y_km: f64,
+    }
+
+    impl Position {
+        pub fn distance_km(&self, other: &Position) -> f64 {
+            (self.x_km - other.x_km).hypot(self.y_km - other.y_km)
+        }
+    }
+
+    /// A hive placed in the landscape.
+    #[derive(Clone, Debug)]
+    pub struct SitedHive {
+        pub envelope: HiveEnvelope,
+        pub position: Position,
+    }
+
+    impl SitedHive {
+        /// Whether a site lies within this hive's forage radius.
+        pub fn forages_at(&self, site: &Position) -> bool {
+            self.position.distance_km(site) <= self.envelope.forage_radius_km
+        }
+    }
+
+    /// Why an adjustment was refused for one hive.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Refusal {
+        /// `policy_allows_adjustment` rejected the predicted envelope.
+        PolicyDenied,
+        /// `safe_step` stopped the predicted step.
+        SafeStepStop,
+        /// A coordinate outside its safe interior would move toward its hard
+        /// edge.
+        TowardHard { var_id: String },
+    }
+
+    /// Predicted effect of an adjustment on one affected hive.
+    #[derive(Clone, Debug)]
+    pub struct HiveImpact {
+        pub hive_id: String,
+        pub distance_km: f64,
+        pub vt_before: f64,
+        pub vt_after: f64,
+        pub refusals: Vec<Refusal>,
+    }
+
+    /// Outcome of an adjustment across the landscape.
+    #[derive(Clone, Debug)]
+    pub struct LandscapeDecision {
+        /// True only if no affected hive refused.
+        pub allowed: bool,
+        /// One entry per affected hive, in landscape order.
+        pub impacts: Vec<HiveImpact>,
+    }
+
+    /// Hives sharing corridors and a decision policy.
+    #[derive(Clone, Debug)]
+    pub struct Landscape {
+        pub hives: Vec<SitedHive>,
+        pub corridors: HiveCorridors,
+        pub policy: DecisionPolicy,
+    }
+
+    impl Landscape {
+        pub fn new(corridors: HiveCorridors) -> Self {
+            Self {
+                hives: Vec::new(),
+                corridors,
+                policy: DecisionPolicy::default(),
+            }
+        }
+
+        pub fn add_hive(&mut self, envelope: HiveEnvelope, position: Position) {
+            self.hives.push(SitedHive { envelope, position });
+        }
+
+        /// Hives whose forage radius covers `site`.
+        pub fn affected_hives<'a>(
+            &'a self,
+            site: &'a Position,
+        ) -> impl Iterator<Item = &'a SitedHive> + 'a {
+            self.hives.iter().filter(move |h| h.forages_at(site))
+        }
+
+        /// Evaluate an adjustment made at `site` for every hive it reaches.
+        ///
+        /// Each affected hive gets its own copy of `adjustment` (with its own
+        /// `hive_id`) and its own predicted envelope from `model`. The
+        /// adjustment is refused if any affected hive fails the policy, is
+        /// stopped by `safe_step`, or has a coordinate pushed toward its hard
+        /// edge — however much it helps the others.
+        pub fn evaluate_adjustment(
+            &self,
+            site: &Position,
+            adjustment: &HiveSystemAdjustment,
+            model: &impl ResponseModel,
+        ) -> LandscapeDecision {
+            let impacts: Vec<HiveImpact> = self
+                .affected_hives(site)
+                .map(|hive| self.impact(hive, site, adjustment, model))
+                .collect();
+            LandscapeDecision {
+                allowed: impacts.iter().all(|i| i.refusals.is_empty()),
+                impacts,
+            }
+        }
+
+        fn impact(
+            &self,
+            hive: &SitedHive,
+            site: &Position,
+            adjustment: &HiveSystemAdjustment,
+            model: &impl ResponseModel,
+        ) -> HiveImpact {
+            let env = &hive.envelope;
+            let adjustment = HiveSystemAdjustment {
+                hive_id: env.hive_id.clone(),
+                ..adjustment.clone()
+            };
+            let predicted = model.predict(env, &adjustment);
+
+            let before = evaluate_hive_with(env, &self.corridors, self.policy);
+            let after = evaluate_hive_with(&predicted, &self.corridors, self.policy);
+
+            let mut refusals = Vec::new();
+            if !policy_allows_adjustment(env, &predicted, &self.corridors) {
+                refusals.push(Refusal::PolicyDenied);
+            }
+            if safe_step(&before, &after).stop {
+                refusals.push(Refusal::SafeStepStop);
+            }
+            for (b, a) in before.coords.iter().zip(&after.coords) {
+                if a.value > 0.0 && a.value > b.value {
+                    refusals.push(Refusal::TowardHard {
+                        var_id: a.var_id.clone(),
+                    });
+                }
+            }
+
+            HiveImpact {
+                hive_id: env.hive_id.clone(),
+                distance_km: hive.position.distance_km(site),
+                vt_before: before.vt,
+                vt_after: after.vt,
+                refusals,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bands::CorridorBands;
+    use super::hive::{
+        compute_ker, eco_impact, evaluate_hive, evaluate_hive_with, knowledge_factor,
+        policy_allows_adjustment, risk_of_harm, AdjustmentRecord, DecisionPolicy, EcoBand,
+        EnvelopeSigma, HiveCorridors, HiveEnvelope, HiveSystemAdjustment,
+    };
+    use super::corridors::{validate_corridors, CorridorLoadError, DiagnosticKind, Tail};
+    use super::reserves::{ReserveConsumption, ReserveCorridors, Season, Seasonal};
+    use super::risk::{to_risk, to_risk_with_sigma, RiskCoord};
+
+    fn corridors() -> HiveCorridors {
+        HiveCorridors {
+            temp_bands: CorridorBands::new("hive_temp", "C", 35.0, 36.0, 38.0, 1.0, 1, true)
+                .with_lower_tail(32.0, 31.0, 28.0),
+            brood_temp_bands: CorridorBands::new("brood_temp", "C", 35.5, 36.0, 37.0, 1.0, 2, true)
+                .with_lower_tail(33.0, 32.5, 30.0),
+            humidity_bands: CorridorBands::new("hive_humidity", "%", 70.0, 80.0, 90.0, 0.5, 3, true)
+                .with_lower_tail(50.0, 40.0, 30.0),
+            toxin_air_bands: CorridorBands::new("toxin_air", "index", 0.1, 0.2, 0.5, 2.0, 4, true),
+            toxin_wax_bands: CorridorBands::new("toxin_wax", "index", 0.1, 0.2, 0.5, 2.0, 5, true),
+            forage_radius_bands: CorridorBands::new("forage_radius", "km", 3.0, 2.0, 0.5, 1.0, 6, true)
+                .lower_is_worse(),
+            forager_load_bands: CorridorBands::new("forager_load", "fraction", 0.6, 0.8, 1.0, 1.0, 7, true),
+            reserves: None,
+        }
+        .with_reserves(reserves())
+    }
+
+    fn reserves() -> ReserveCorridors {
+        let store = |var_id: &str, safe: f64, gold: f64, hard: f64| {
+            CorridorBands::new(var_id, "kg", safe, gold, hard, 1.0, 8, true).lower_is_worse()
+        };
+        ReserveCorridors {
+            nectar: Seasonal {
+                spring: store("nectar", 10.0, 6.0, 3.0),
+                summer: store("nectar", 8.0, 5.0, 2.0),
+                autumn: store("nectar", 15.0, 10.0, 5.0),
+                winter: store("nectar", 20.0, 15.0, 8.0),
+            },
+            pollen: Seasonal {
+                spring: store("pollen", 1.0, 0.6, 0.3),
+                summer: store("pollen", 0.8, 0.5, 0.2),
+                autumn: store("pollen", 1.0, 0.6, 0.3),
+                winter: store("pollen", 0.3, 0.2, 0.1),
+            },
+            days_of_reserve: CorridorBands::new("days_of_reserve", "days", 21.0, 14.0, 5.0, 1.0, 9, true)
+                .lower_is_worse(),
+            consumption: ReserveConsumption {
+                nectar_kg_per_day: Seasonal { spring: 0.3, summer: 0.3, autumn: 0.15, winter: 0.08 },
+                pollen_kg_per_day: Seasonal { spring: 0.1, summer: 0.05, autumn: 0.04, winter: 0.01 },
+                load_gain: 0.5,
+            },
+        }
+    }
+
+    /// `corridors()` without reserves, as a corridor file.
+    const CORRIDOR_TOML: &str = r#"
+[temp]
+var_id = "hive_temp"
+units = "C"
+upper = { safe = 35.0, gold = 36.0, hard = 38.0 }
+lower = { safe = 32.0, gold = 31.0, hard = 28.0 }
+weight = 1.0
+lyap_channel = 1
+
+[brood_temp]
+var_id = "brood_temp"
+units = "C"
+upper = { safe = 35.5, gold = 36.0, hard = 37.0 }
+lower = { safe = 33.0, gold = 32.5, hard = 30.0 }
+weight = 1.0
+lyap_channel = 2
+
+[humidity]
+var_id = "hive_humidity"
+units = "%"
+upper = { safe = 70.0, gold = 80.0, hard = 90.0 }
+lower = { safe = 50.0, gold = 40.0, hard = 30.0 }
+weight = 0.5
+lyap_channel = 3
+
+[toxin_air]
+var_id = "toxin_air"
+units = "index"
+upper = { safe = 0.1, gold = 0.2, hard = 0.5 }
+weight = 2.0
+lyap_channel = 4
+
+[toxin_wax]
+var_id = "toxin_wax"
+units = "index"
+upper = { safe = 0.1, gold = 0.2, hard = 0.5 }
+weight = 2.0
+lyap_channel = 5
+
+[forage_radius]
+var_id = "forage_radius"
+units = "km"
+lower = { safe = 3.0, gold = 2.0, hard = 0.5 }
+weight = 1.0
+lyap_channel = 6
+
+[forager_load]
+var_id = "forager_load"
+units = "fraction"
+upper = { safe = 0.6, gold = 0.8, hard = 1.0 }
+weight = 1.0
+lyap_channel = 7
+"#;
+
+    fn load_err(text: &str) -> CorridorLoadError {
+        HiveCorridors::from_toml_str(text).expect_err("corridor file should be rejected")
+    }
+
+    fn invalid_kinds(text: &str) -> Vec<DiagnosticKind> {
+        match load_err(text) {
+            CorridorLoadError::Invalid(diagnostics) => {
+                diagnostics.into_iter().map(|d| d.kind).collect()
+            }
+            CorridorLoadError::Parse(msg) => panic!("unexpected parse error: {msg}"),
+        }
+    }
+
+    fn envelope() -> HiveEnvelope {
+        HiveEnvelope {
+            hive_id: "hive-001".to_string(),
+            region: "phoenix".to_string(),
+            brood_temp_c: 34.5,
+            hive_temp_c: 34.0,
+            hive_humidity_pct: 60.0,
+            nectar_kg: 10.0,
+            pollen_kg: 2.0,
+            forager_load_pct: 0.5,
+            toxin_index_air: 0.05,
+            toxin_index_wax: 0.05,
+            forage_radius_km: 4.0,
+            season: Season::Summer,
+            sigma: EnvelopeSigma::default(),
+        }
+    }
+
+    fn band_with(set: impl Fn(&mut HiveEnvelope)) -> EcoBand {
+        let mut env = envelope();
+        set(&mut env);
+        env.eco_band(&corridors())
+    }
+
+    #[test]
+    fn corridor_file_loads_and_matches_hand_built_corridors() {
+        let loaded = HiveCorridors::from_toml_str(CORRIDOR_TOML).unwrap();
+        let built = corridors();
+        for ((slot, a), (_, b)) in loaded.slots().into_iter().zip(built.slots()) {
+            assert_eq!(a.var_id, b.var_id, "{slot}");
+            assert_eq!(a.direction, b.direction, "{slot}");
+            assert_eq!((a.safe, a.gold, a.hard), (b.safe, b.gold, b.hard), "{slot}");
+            assert!(a.mandatory, "{slot}");
+        }
+        assert!(loaded.reserves.is_none());
+        assert!(validate_corridors(&loaded).is_empty());
+        // Hand-built corridors also carry the reserve bands (kg, days).
+        assert!(built.reserves.is_some());
+        assert_eq!(validate_corridors(&built), []);
+    }
+
+    #[test]
+    fn corridor_file_rejects_unknown_fields_and_malformed_bands() {
+        let unknown_field = CORRIDOR_TOML.replace("lyap_channel = 4", "lyap_channel = 4\ncolour = \"red\"");
+        assert!(matches!(load_err(&unknown_field), CorridorLoadError::Parse(msg) if msg.contains("colour")));
+
+        let gold_beyond_hard = CORRIDOR_TOML.replace(
+            "upper = { safe = 0.6, gold = 0.8, hard = 1.0 }",
+            "upper = { safe = 0.6, gold = 1.2, hard = 1.0 }",
+        );
+        assert_eq!(
+            invalid_kinds(&gold_beyond_hard),
+            [DiagnosticKind::GoldBeyondHard { tail: Tail::Upper, gold: 1.2, hard: 1.0 }]
+        );
+
+        let overlap = CORRIDOR_TOML.replace(
+            "lower = { safe = 50.0, gold = 40.0, hard = 30.0 }",
+            "lower = { safe = 75.0, gold = 40.0, hard = 30.0 }",
+        );
+        assert_eq!(
+            invalid_kinds(&overlap),
+            [DiagnosticKind::TailsOverlap { lower_safe: 75.0, upper_safe: 70.0 }]
+        );
+
+        let non_finite = CORRIDOR_TOML.replace(
+            "lower = { safe = 3.0, gold = 2.0, hard = 0.5 }",
+            "lower = { safe = 3.0, gold = 2.0, hard = nan }",
+        );
+        assert_eq!(invalid_kinds(&non_finite), [DiagnosticKind::NonFiniteEdge]);
+
+        // An advisory band is not held to the admission checks.
+        let advisory = non_finite.replace("lyap_channel = 6", "lyap_channel = 6\nmandatory = false");
+        let loaded = HiveCorridors::from_toml_str(&advisory).unwrap();
+        assert!(!loaded.forage_radius_bands.mandatory);
+        assert!(validate_corridors(&loaded).is_empty());
+    }
+
+    #[test]
+    fn baseline_is_safe_with_zero_residual() {
+        let residual = evaluate_hive(&envelope(), &corridors());
+        assert_eq!(residual.vt, 0.0);
+        assert_eq!(residual.eco_band(), EcoBand::Safe);
+    }
+
+    #[test]
+    fn upper_tail_transitions_compare_in_risk_space() {
+        // r_gold = (36 - 35) / (38 - 35) = 1/3. The old raw comparison
+        // (r > 36.0) could never derate.
+        let path = [
+            (35.5, EcoBand::Safe),
+            (36.5, EcoBand::Warning),
+            (38.0, EcoBand::Critical),
+            (36.5, EcoBand::Warning),
+            (35.5, EcoBand::Safe),
+            (34.0, EcoBand::Safe),
+        ];
+        for (t, expected) in path {
+            assert_eq!(band_with(|e| e.hive_temp_c = t), expected, "hive temp {t}");
+        }
+    }
+
+    #[test]
+    fn lower_tail_transitions() {
+        // Forage radius: r_gold = (3 - 2) / (3 - 0.5) = 0.4.
+        let path = [
+            (2.5, EcoBand::Safe),
+            (1.5, EcoBand::Warning),
+            (0.4, EcoBand::Critical),
+            (1.5, EcoBand::Warning),
+            (4.0, EcoBand::Safe),
+        ];
+        for (km, expected) in path {
+            assert_eq!(band_with(|e| e.forage_radius_km = km), expected, "forage {km} km");
+        }
+        // Chilled hive on the lower tail of a two-sided band: r_gold = 1/4.
+        assert_eq!(band_with(|e| e.hive_temp_c = 31.5), EcoBand::Safe);
+        assert_eq!(band_with(|e| e.hive_temp_c = 30.0), EcoBand::Warning);
+        assert_eq!(band_with(|e| e.hive_temp_c = 27.0), EcoBand::Critical);
+    }
+
+    #[test]
+    fn two_sided_transitions_use_active_tail() {
+        let path = [
+            (45.0, EcoBand::Safe),
+            (35.0, EcoBand::Warning),
+            (25.0, EcoBand::Critical),
+            (60.0, EcoBand::Safe),
+            (75.0, EcoBand::Safe),
+            (85.0, EcoBand::Warning),
+            (95.0, EcoBand::Critical),
+        ];
+        for (rh, expected) in path {
+            assert_eq!(band_with(|e| e.hive_humidity_pct = rh), expected, "humidity {rh} %");
+        }
+    }
+
+    #[test]
+    fn to_risk_uses_the_reading_side_tail() {
+        let temp = corridors().temp_bands;
+        assert_eq!(to_risk(33.5, &temp), 0.0);
+        assert!((to_risk(36.5, &temp) - 0.5).abs() < 1e-9);
+        assert!((to_risk(30.0, &temp) - 0.5).abs() < 1e-9);
+        assert_eq!(to_risk(40.0, &temp), 1.0);
+        assert_eq!(to_risk(20.0, &temp), 1.0);
+        assert!((temp.r_gold(36.5) - 1.0 / 3.0).abs() < 1e-9);
+        assert!((temp.r_gold(30.0) - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn non_finite_reading_is_at_the_hard_edge() {
+        let toxin = corridors().toxin_air_bands;
+        assert_eq!(to_risk(f64::NAN, &toxin), 1.0);
+        assert_eq!(to_risk(f64::INFINITY, &toxin), 1.0);
+        assert_eq!(band_with(|e| e.toxin_index_air = f64::NAN), EcoBand::Critical);
+        assert_eq!(band_with(|e| e.hive_humidity_pct = f64::NAN), EcoBand::Critical);
+    }
+
+    #[test]
+    fn upper_confidence_bound_is_conservative() {
+        let mut env = envelope();
+        env.hive_temp_c = 35.9; // r = 0.3, just inside gold
+        let point = evaluate_hive(&env, &corridors());
+        assert_eq!(point.eco_band(), EcoBand::Safe);
+
+        let ucb = evaluate_hive_with(&env, &corridors(), DecisionPolicy::upper_confidence());
+        assert_eq!(ucb.eco_band(), EcoBand::Warning);
+        let (lo, hi) = ucb.vt_interval(1.0);
+        assert!(lo < ucb.vt && ucb.vt < hi);
+    }
+
+    #[test]
+    fn sigma_propagates_through_the_local_band_slope() {
+        // Toxin band: safe 0.1, hard 0.5, so the slope is 2.5 per index unit.
+        let toxin = corridors().toxin_air_bands;
+        let (r, sigma) = to_risk_with_sigma(0.3, 0.04, &toxin);
+        assert!((r - 0.5).abs() < 1e-9);
+        assert!((sigma - 0.1).abs() < 1e-9);
+        assert_eq!(to_risk_with_sigma(0.3, -0.04, &toxin), (r, sigma));
+        // At the safe edge risk can only rise.
+        let (_, sigma) = to_risk_with_sigma(0.1, 0.04, &toxin);
+        assert!((sigma - 0.1).abs() < 1e-9);
+        // Flat deep inside the safe interior and beyond the hard edge.
+        assert_eq!(to_risk_with_sigma(0.0, 0.04, &toxin), (0.0, 0.0));
+        assert_eq!(to_risk_with_sigma(0.9, 0.04, &toxin), (1.0, 0.0));
+
+        // A wide sigma on a two-sided band reaches into both tails; the
+        // worse one sets the risk sigma: (37.5 - 35) / (38 - 35).
+        let temp = corridors().temp_bands;
+        let (r, sigma) = to_risk_with_sigma(33.5, 4.0, &temp);
+        assert_eq!(r, 0.0);
+        assert!((sigma - 2.5 / 3.0).abs() < 1e-9);
+        assert_eq!(to_risk_with_sigma(36.5, f64::INFINITY, &temp).1, 1.0);
+        assert_eq!(to_risk_with_sigma(36.5, f64::NAN, &temp).1, 1.0);
+    }
+
+    #[test]
+    fn upper_confidence_adds_1_645_sigma() {
+        assert_eq!(DecisionPolicy::default().confidence_k, 0.0);
+        let k = DecisionPolicy::upper_confidence().confidence_k;
+        assert_eq!(k, 1.645);
+
+        let coord = RiskCoord::new(0.3, 0.04, &corridors().toxin_air_bands);
+        assert!((coord.upper_bound(k) - (0.5 + 1.645 * 0.1)).abs() < 1e-9);
+        assert_eq!(coord.upper_bound(10.0), 1.0);
+    }
+
+    #[test]
+    fn noisier_sensors_decide_more_conservatively() {
+        let c = corridors();
+        let policy = DecisionPolicy::upper_confidence();
+        let mut env = envelope();
+        env.hive_temp_c = 35.1; // r = 1/30, gold at 1/3
+        assert_eq!(evaluate_hive_with(&env, &c, policy).eco_band(), EcoBand::Safe);
+
+        env.sigma.hive_temp_c = 1.0;
+        let noisy = evaluate_hive_with(&env, &c, policy);
+        assert_eq!(noisy.eco_band(), EcoBand::Warning);
+        assert!(noisy.vt_sigma > 0.0);
+
+        // A badly instrumented hive is not safe just because it reads mid-band.
+        env.hive_temp_c = 33.5;
+        env.sigma.hive_temp_c = 4.0;
+        assert_ne!(evaluate_hive_with(&env, &c, policy).eco_band(), EcoBand::Safe);
+
+        // Without uncertainty the upper bound is the point estimate.
+        env.sigma = EnvelopeSigma {
+            brood_temp_c: 0.0,
+            hive_temp_c: 0.0,
+            hive_humidity_pct: 0.0,
+            nectar_kg: 0.0,
+            pollen_kg: 0.0,
+            forager_load_pct: 0.0,
+            toxin_index_air: 0.0,
+            toxin_index_wax: 0.0,
+            forage_radius_km: 0.0,
+        };
+        env.hive_temp_c = 35.9;
+        let exact = evaluate_hive_with(&env, &c, policy);
+        assert_eq!(exact.vt_sigma, 0.0);
+        assert_eq!(exact.eco_band(), EcoBand::Safe);
+    }
+
+    #[test]
+    fn policy_rejects_leaving_temperature_safe_band() {
+        let before = envelope();
+        let mut after = envelope();
+        after.hive_temp_c = 35.5;
+        assert!(!policy_allows_adjustment(&before, &after, &corridors()));
+
+        after.hive_temp_c = 34.5;
+        assert!(policy_allows_adjustment(&before, &after, &corridors()));
+
+        // Already outside the safe band: cooling is allowed, heating is not.
+        let mut before = envelope();
+        before.hive_temp_c = 36.0;
+        after.hive_temp_c = 36.5;
+        assert!(!policy_allows_adjustment(&before, &after, &corridors()));
+        after.hive_temp_c = 35.5;
+        assert!(policy_allows_adjustment(&before, &after, &corridors()));
+    }
+
+    #[test]
+    fn planner_ranks_admissible_adjustments_by_predicted_residual() {
+        use super::planner::{plan_adjustments, LinearResponseModel, PlannerConfig};
+
+        let mut env = envelope();
+        env.hive_temp_c = 36.5;
+        env.toxin_index_air = 0.3;
+
+        let mut model = LinearResponseModel::default();
+        // Shade from wildflower strips cools the hive; less spraying cuts
+        // airborne toxins; irrigation adds humidity and (wrongly here) heat.
+        model.per_wildflower_m2.hive_temp_c = -0.001;
+        model.per_pesticide_pct.toxin_index_air = 0.004;
+        model.per_irrigation_m3_per_day.hive_temp_c = 0.5;
+
+        let plans = plan_adjustments(&env, &corridors(), &model, &PlannerConfig::default());
+        assert!(!plans.is_empty());
+        let best = &plans[0];
+        assert_eq!(best.adjustment.delta_wildflower_area_m2, 1000.0);
+        assert_eq!(best.adjustment.delta_pesticide_use_pct, -50.0);
+        assert_eq!(best.adjustment.delta_irrigation_m3_per_day, 0.0);
+        // Light and corridor have no modelled effect, so the lightest touch wins.
+        assert_eq!(best.adjustment.delta_light_pollution_lm, 0.0);
+        assert!(best.vt_predicted < best.vt_before);
+        assert!(best.adjustment.rationale.contains("toxin_air"));
+        assert!(plans.windows(2).all(|w| w[0].vt_predicted <= w[1].vt_predicted));
+        // No admissible plan may raise toxins or V_t.
+        assert!(plans.iter().all(|p| p.predicted.toxin_index_air <= env.toxin_index_air));
+    }
+
+    #[test]
+    fn ker_is_derived_from_coverage_penetration_and_history() {
+        let c = corridors();
+        let mut env = envelope();
+        let policy = DecisionPolicy::default();
+
+        let ker = compute_ker(&env, &c, &[], policy);
+        assert!(ker.knowledge_factor > 0.0 && ker.knowledge_factor < 1.0);
+        assert_eq!(ker.risk_of_harm, 0.0);
+        assert_eq!(ker.eco_impact, 0.0);
+
+        // A missing reading and a noisier sensor both cost knowledge.
+        let full = ker.knowledge_factor;
+        env.sigma.hive_temp_c = 2.0;
+        assert!(knowledge_factor(&env, &c) < full);
+        env.toxin_index_wax = f64::NAN;
+        let partial = knowledge_factor(&env, &c);
+        // An unknown reading is never reported as safe.
+        let unknown = compute_ker(&env, &c, &[], policy);
+        assert_eq!(unknown.risk_of_harm, 1.0);
+        assert_eq!(evaluate_hive(&env, &c).eco_band(), EcoBand::Critical);
+        env.toxin_index_wax = 0.05;
+        env.sigma.hive_temp_c = 0.5;
+        assert!(partial < knowledge_factor(&env, &c));
+
+        // Risk of harm is the deepest penetration: toxin air halfway to hard.
+        env.toxin_index_air = 0.3;
+        let r = risk_of_harm(&evaluate_hive(&env, &c), policy);
+        assert!((r - 0.5).abs() < 1e-9);
+
+        let adj = |vt_before: f64, vt_after: f64| AdjustmentRecord {
+            adjustment: HiveSystemAdjustment {
+                hive_id: "H1".into(),
+                delta_wildflower_area_m2: 500.0,
+                delta_pesticide_use_pct: 0.0,
+                delta_irrigation_m3_per_day: 0.0,
+                delta_light_pollution_lm: 0.0,
+                delta_foraging_corridor_km: 0.0,
+                rationale: String::new(),
+            },
+            vt_before,
+            vt_after,
+        };
+        assert!((eco_impact(&[adj(1.0, 0.5), adj(0.4, 0.2)]) - 0.5).abs() < 1e-9);
+        assert_eq!(eco_impact(&[adj(0.0, 0.3)]), 0.0);
+    }
+
+    #[test]
+    fn residual_history_tolerates_noise_and_certifies_decrease() {
+        use super::history::{HistoryConfig, ResidualHistory};
+
+        let c = corridors();
+        let config = HistoryConfig {
+            window: 4,
+            noise_tolerance: 0.01,
+            sigma_k: 0.0,
+        };
+        let mut history = ResidualHistory::new(config);
+        let mut env = envelope();
+        env.toxin_index_air = 0.4;
+
+        // Recovering toxins, with a noise-sized uptick along the way.
+        for (t, toxin) in [(0, 0.4), (1, 0.35), (2, 0.351), (3, 0.3)] {
+            env.toxin_index_air = toxin;
+            assert!(history.push(t, evaluate_hive(&env, &c)).is_none());
+        }
+        let cert = history.certificate().expect("monotone window");
+        assert_eq!((cert.t_start, cert.t_end, cert.samples), (0, 3, 4));
+        assert!(cert.max_delta_v > 0.0 && cert.max_delta_v <= 0.01);
+        assert!(cert.vt_end < cert.vt_start);
+        assert_eq!(cert.coords.len(), 10);
+
+        // A real rise is a violation attributed to the brood temperature.
+        env.brood_temp_c = 36.8;
+        let v = history.push(4, evaluate_hive(&env, &c)).expect("violation");
+        assert_eq!(v.driver, "brood_temp");
+        assert!(v.delta_v > v.tolerance);
+        assert!(history.certificate().is_none());
+        assert_eq!(history.len(), 4);
+
+        // Once the rise leaves the window, the violation goes with it.
+        for t in 5..9 {
+            history.push(t, evaluate_hive(&env, &c));
+        }
+        assert_eq!(history.violations().count(), 0);
+        assert!(history.certificate().is_some());
+    }
+
+    #[test]
+    fn residual_history_refuses_to_certify_slow_creep() {
+        use super::history::{HistoryConfig, ResidualHistory};
+
+        let c = corridors();
+        let mut history = ResidualHistory::new(HistoryConfig {
+            window: 4,
+            noise_tolerance: 0.01,
+            sigma_k: 0.0,
+        });
+        let mut env = envelope();
+        // Each step adds 2 * 2.5 * 0.0016 = 0.008 to V_t: within the step
+        // tolerance, but 0.024 over the window.
+        for t in 0..4 {
+            env.toxin_index_air = 0.3 + 0.0016 * t as f64;
+            assert!(history.push(t, evaluate_hive(&env, &c)).is_none());
+        }
+        assert_eq!(history.violations().count(), 0);
+        assert!(history.certificate().is_none());
+    }
+
+    #[test]
+    fn landscape_refuses_adjustment_that_harms_any_affected_hive() {
+        use super::landscape::{Landscape, Position, Refusal};
+        use super::planner::LinearResponseModel;
+
+        let mut landscape = Landscape::new(corridors());
+        let mut hot = envelope();
+        hot.hive_id = "hot".into();
+        hot.hive_temp_c = 36.5;
+        let mut humid = envelope();
+        humid.hive_id = "humid".into();
+        humid.hive_humidity_pct = 75.0;
+        let mut far = envelope();
+        far.hive_id = "far".into();
+        landscape.add_hive(hot, Position { x_km: 0.0, y_km: 0.0 });
+        landscape.add_hive(humid, Position { x_km: 4.0, y_km: 0.0 });
+        landscape.add_hive(far, Position { x_km: 20.0, y_km: 0.0 });
+
+        // Irrigation cools hives but raises their humidity.
+        let mut model = LinearResponseModel::default();
+        model.per_irrigation_m3_per_day.hive_temp_c = -0.4;
+        model.per_irrigation_m3_per_day.hive_humidity_pct = 3.0;
+        let irrigate = HiveSystemAdjustment {
+            hive_id: String::new(),
+            delta_wildflower_area_m2: 0.0,
+            delta_pesticide_use_pct: 0.0,
+            delta_irrigation_m3_per_day: 1.0,
+            delta_light_pollution_lm: 0.0,
+            delta_foraging_corridor_km: 0.0,
+            rationale: String::new(),
+        };
+
+        // Near the hot hive only: it helps and is allowed.
+        let site = Position { x_km: -1.0, y_km: 0.0 };
+        let d = landscape.evaluate_adjustment(&site, &irrigate, &model);
+        assert!(d.allowed);
+        assert_eq!(d.impacts.len(), 1);
+        assert!(d.impacts[0].vt_after < d.impacts[0].vt_before);
+
+        // Between the two: the humid hive would move toward its hard edge.
+        let site = Position { x_km: 2.0, y_km: 0.0 };
+        let ids: Vec<_> = landscape
+            .affected_hives(&site)
+            .map(|h| h.envelope.hive_id.as_str())
+            .collect();
+        assert_eq!(ids, ["hot", "humid"]);
+        let d = landscape.evaluate_adjustment(&site, &irrigate, &model);
+        assert!(!d.allowed);
+        assert!(d.impacts[0].refusals.is_empty());
+        assert!(d.impacts[1].refusals.contains(&Refusal::TowardHard {
+            var_id: "hive_humidity".into()
+        }));
+    }
+
+    #[test]
+    fn starving_colony_is_not_safe() {
+        let c = corridors();
+        // Summer draw: 0.3 * (1 + 0.5 * 0.5) = 0.375 kg/day -> ~27 days.
+        let (days, _) = c.reserves.as_ref().unwrap().days_of_reserve(&envelope());
+        assert!((days - 10.0 / 0.375).abs() < 1e-9);
+
+        let starving = band_with(|e| {
+            e.nectar_kg = 1.0;
+            e.pollen_kg = 0.1;
+        });
+        assert_eq!(starving, EcoBand::Critical);
+
+        // Stores that carry a summer colony fall short of the winter minimum.
+        assert_eq!(band_with(|e| e.season = Season::Winter), EcoBand::Warning);
+
+        // Heavy foraging drains stores faster: 5.5 kg at 0.3 * 1.5 kg/day is
+        // ~12 days, past the 14-day gold edge.
+        let mut env = envelope();
+        env.nectar_kg = 5.5;
+        env.forager_load_pct = 1.0;
+        let residual = evaluate_hive(&env, &c);
+        let days = residual.coords.iter().find(|r| r.var_id == "days_of_reserve").unwrap();
+        assert!(days.value > days.r_gold);
+    }
+
+    #[test]
+    fn colony_without_draw_is_not_stopped() {
+        let mut c = corridors();
+        let reserves = c.reserves.as_mut().unwrap();
+        reserves.consumption.nectar_kg_per_day.winter = 0.0;
+        reserves.consumption.pollen_kg_per_day.winter = 0.0;
+        let mut env = envelope();
+        env.season = Season::Winter;
+        env.nectar_kg = 50.0;
+
+        assert_eq!(reserves.days_of_reserve(&env), (f64::MAX, 0.0));
+        let residual = evaluate_hive(&env, &c);
+        let days = residual.coords.iter().find(|r| r.var_id == "days_of_reserve").unwrap();
+        assert_eq!((days.value, days.sigma), (0.0, 0.0));
+        assert_eq!(residual.eco_band(), EcoBand::Safe);
+    }
+}
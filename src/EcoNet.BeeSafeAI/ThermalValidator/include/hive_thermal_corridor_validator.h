@@ -0,0 +1,194 @@
+#ifndef HIVE_THERMAL_CORRIDOR_VALIDATOR_H
+#define HIVE_THERMAL_CORRIDOR_VALIDATOR_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Status code returned by every fallible FFI call.
+typedef enum HiveStatus {
+  HIVE_STATUS_OK = 0,
+  // A required pointer argument was null.
+  HIVE_STATUS_NULL_POINTER = 1,
+  // A string argument was not valid UTF-8.
+  HIVE_STATUS_INVALID_UTF8 = 2,
+  // A JSON argument could not be parsed.
+  HIVE_STATUS_INVALID_JSON = 3,
+  // A numeric argument (e.g., a timestamp or index) was out of range.
+  HIVE_STATUS_INVALID_ARGUMENT = 4,
+  // [`ValidationError::EmptySeries`].
+  HIVE_STATUS_EMPTY_SERIES = 5,
+  // [`ValidationError::MixedHiveIds`].
+  HIVE_STATUS_MIXED_HIVE_IDS = 6,
+  // [`ValidationError::InsufficientHistory`].
+  HIVE_STATUS_INSUFFICIENT_HISTORY = 7,
+  // The validator panicked; the handle should be freed and not reused.
+  HIVE_STATUS_PANIC = 8,
+} HiveStatus;
+
+// Violation kind as seen from C.
+typedef enum HiveViolationKind {
+  HIVE_VIOLATION_KIND_HIVE_INTERNAL_OVERHEAT = 0,
+  HIVE_VIOLATION_KIND_BEE_BRAIN_OVERHEAT = 1,
+  HIVE_VIOLATION_KIND_HIVE_WBGT_OVERHEAT = 2,
+  HIVE_VIOLATION_KIND_COOLDOWN_TOO_SLOW = 3,
+  HIVE_VIOLATION_KIND_HIVE_INTERNAL_CHILL = 4,
+  HIVE_VIOLATION_KIND_HUMIDITY_TOO_LOW = 5,
+  HIVE_VIOLATION_KIND_HUMIDITY_TOO_HIGH = 6,
+  HIVE_VIOLATION_KIND_WARMUP_TOO_SLOW = 7,
+  HIVE_VIOLATION_KIND_SUSTAINED_EXCEEDANCE = 8,
+} HiveViolationKind;
+
+// Opaque corridor handle.
+typedef struct HiveCorridor HiveCorridor;
+
+// Opaque validation result handle.
+typedef struct HiveResult HiveResult;
+
+// Opaque streaming validator handle.
+typedef struct HiveValidator HiveValidator;
+
+// One sample passed by value from C. `brain_temp_approx_c` is NaN when not
+// yet estimated; any non-finite value is read as missing.
+typedef struct HiveSampleC {
+  // Milliseconds since the Unix epoch (UTC).
+  int64_t timestamp_unix_ms;
+  // NUL-terminated UTF-8 hive identifier.
+  const char *hive_id;
+  float ambient_temp_c;
+  float hive_internal_temp_c;
+  float wbgt_c;
+  float solar_irradiance_w_m2;
+  float relative_humidity_pct;
+  float brain_temp_approx_c;
+} HiveSampleC;
+
+// Headline numbers of a validation result.
+typedef struct HiveResultSummaryC {
+  uint64_t total_samples;
+  uint64_t violation_count;
+  float safe_fraction;
+  float data_coverage_fraction;
+  float hb_score;
+  bool is_beesafe_compliant;
+} HiveResultSummaryC;
+
+// One violation, flattened. Sustained-exceedance details are only available
+// through [`hive_result_to_json`].
+typedef struct HiveViolationC {
+  int64_t timestamp_unix_ms;
+  enum HiveViolationKind kind;
+  float value;
+  float threshold;
+} HiveViolationC;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// NUL-terminated library version string; statically allocated, do not free.
+const char *hive_validator_version(void);
+
+// Message for the last failed call on this thread, or null if none. Valid
+// until the next failing call on the same thread; do not free.
+const char *hive_last_error_message(void);
+
+// New corridor with the default bee neural-safety limits.
+struct HiveCorridor *hive_corridor_default(void);
+
+// Parse a corridor from JSON (the serde form of [`HiveThermalCorridor`]).
+//
+// # Safety
+// `json` must be a valid NUL-terminated string and `out` a valid pointer.
+enum HiveStatus hive_corridor_from_json(const char *json, struct HiveCorridor **out);
+
+// # Safety
+// `corridor` must be null or a pointer returned by this library, freed once.
+void hive_corridor_free(struct HiveCorridor *corridor);
+
+// New streaming validator over a copy of `corridor`; null if `corridor` is null.
+//
+// # Safety
+// `corridor` must be null or a live corridor handle.
+struct HiveValidator *hive_validator_new(const struct HiveCorridor *corridor);
+
+// Push one sample. On error the validator state is unchanged.
+//
+// # Safety
+// `validator` must be a live validator handle and `sample` a valid pointer.
+enum HiveStatus hive_validator_push(struct HiveValidator *validator,
+                                    const struct HiveSampleC *sample);
+
+// Push a contiguous array of `len` samples, stopping at the first error.
+// `pushed` (optional) receives the number of samples accepted.
+//
+// # Safety
+// `samples` must point to `len` valid samples; `pushed` may be null.
+enum HiveStatus hive_validator_push_array(struct HiveValidator *validator,
+                                          const struct HiveSampleC *samples,
+                                          size_t len,
+                                          size_t *pushed);
+
+// Push a JSON array of samples (the serde form of [`HiveThermalSample`]),
+// stopping at the first error.
+//
+// # Safety
+// `validator` must be a live validator handle and `json` a valid string.
+enum HiveStatus hive_validator_push_json(struct HiveValidator *validator, const char *json);
+
+// Result for everything pushed so far; the validator stays usable.
+//
+// # Safety
+// `validator` must be a live validator handle and `out` a valid pointer.
+enum HiveStatus hive_validator_snapshot(const struct HiveValidator *validator,
+                                        struct HiveResult **out);
+
+// # Safety
+// `validator` must be null or a pointer returned by this library, freed once.
+void hive_validator_free(struct HiveValidator *validator);
+
+// One-shot batch validation of a JSON sample array, as
+// [`validate_hive_series`](crate::validate_hive_series).
+//
+// # Safety
+// `corridor` must be a live corridor handle, `samples_json` a valid string
+// and `out` a valid pointer.
+enum HiveStatus hive_validate_series_json(const struct HiveCorridor *corridor,
+                                          const char *samples_json,
+                                          struct HiveResult **out);
+
+// # Safety
+// `result` must be a live result handle and `out` a valid pointer.
+enum HiveStatus hive_result_summary(const struct HiveResult *result,
+                                    struct HiveResultSummaryC *out);
+
+// Violation at `index` (0-based, below `violation_count`).
+//
+// # Safety
+// `result` must be a live result handle and `out` a valid pointer.
+enum HiveStatus hive_result_violation(const struct HiveResult *result,
+                                      size_t index,
+                                      struct HiveViolationC *out);
+
+// Full result as JSON, including warnings, breakdown and exposure. Free the
+// returned string with [`hive_string_free`]; null on failure.
+//
+// # Safety
+// `result` must be a live result handle.
+char *hive_result_to_json(const struct HiveResult *result);
+
+// # Safety
+// `result` must be null or a pointer returned by this library, freed once.
+void hive_result_free(struct HiveResult *result);
+
+// # Safety
+// `s` must be null or a string returned by this library, freed once.
+void hive_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HIVE_THERMAL_CORRIDOR_VALIDATOR_H */
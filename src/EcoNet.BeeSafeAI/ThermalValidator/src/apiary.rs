@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    validate_hive_series, HiveThermalCorridor, HiveThermalSample, HiveThermalValidationResult,
+    ValidationError,
+};
+
+/// Corridors for an apiary: one shared corridor, optionally overridden per hive
+/// (e.g., a nucleus colony or a hive under a shade structure).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiaryCorridors {
+    pub default: HiveThermalCorridor,
+    pub per_hive: HashMap<String, HiveThermalCorridor>,
+}
+
+impl ApiaryCorridors {
+    /// Every hive is validated against the same corridor.
+    pub fn shared(corridor: HiveThermalCorridor) -> Self {
+        Self {
+            default: corridor,
+            per_hive: HashMap::new(),
+        }
+    }
+
+    pub fn with_hive(mut self, hive_id: impl Into<String>, corridor: HiveThermalCorridor) -> Self {
+        self.per_hive.insert(hive_id.into(), corridor);
+        self
+    }
+
+    pub fn for_hive(&self, hive_id: &str) -> &HiveThermalCorridor {
+        self.per_hive.get(hive_id).unwrap_or(&self.default)
+    }
+}
+
+/// Spread of HB scores across the hives of an apiary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HbDistribution {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    pub median: f32,
+}
+
+/// Apiary-wide summary built from per-hive validation results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiaryValidationSummary {
+    /// Per-hive results, ordered by hive ID.
+    pub hives: Vec<HiveThermalValidationResult>,
+    /// Hive with the lowest HB score (first by hive ID on ties).
+    pub worst_hive_id: String,
+    /// Fraction (0–1) of hives that are BeeSafe-compliant.
+    pub compliant_fraction: f32,
+    pub hb_distribution: HbDistribution,
+}
+
+/// Validate an apiary-wide export containing samples for many hives.
+///
+/// Samples may arrive in any order; they are grouped by `hive_id`, sorted by
+/// timestamp within each hive, and each hive is validated against its corridor
+/// from `corridors`. Unlike [`validate_hive_series`], mixed hive IDs are expected.
+pub fn validate_apiary(
+    corridors: &ApiaryCorridors,
+    samples: &[HiveThermalSample],
+) -> Result<ApiaryValidationSummary, ValidationError> {
+    if samples.is_empty() {
+        return Err(ValidationError::EmptySeries);
+    }
+
+    let mut by_hive: BTreeMap<&str, Vec<HiveThermalSample>> = BTreeMap::new();
+    for s in samples {
+        by_hive
+            .entry(s.hive_id.as_str())
+            .or_default()
+            .push(s.clone());
+    }
+
+    let mut hives = Vec::with_capacity(by_hive.len());
+    for (hive_id, mut series) in by_hive {
+        // Stable sort keeps export order for duplicate timestamps.
+        series.sort_by_key(|s| s.timestamp);
+        hives.push(validate_hive_series(corridors.for_hive(hive_id), &series)?);
+    }
+
+    let worst_hive_id = hives
+        .iter()
+        .fold(
+            None::<&HiveThermalValidationResult>,
+            |worst, r| match worst {
+                Some(w) if w.hb_score <= r.hb_score => Some(w),
+                _ => Some(r),
+            },
+        )
+        .map(|r| r.hive_id.clone())
+        .unwrap_or_default();
+
+    let compliant = hives.iter().filter(|r| r.is_beesafe_compliant).count();
+    let compliant_fraction = compliant as f32 / hives.len() as f32;
+
+    let mut scores: Vec<f32> = hives.iter().map(|r| r.hb_score).collect();
+    scores.sort_by(|a, b| a.total_cmp(b));
+    let n = scores.len();
+    let median = if n % 2 == 1 {
+        scores[n / 2]
+    } else {
+        0.5 * (scores[n / 2 - 1] + scores[n / 2])
+    };
+    let hb_distribution = HbDistribution {
+        min: scores[0],
+        max: scores[n - 1],
+        mean: scores.iter().sum::<f32>() / n as f32,
+        median,
+    };
+
+    Ok(ApiaryValidationSummary {
+        hives,
+        worst_hive_id,
+        compliant_fraction,
+        hb_distribution,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default_bee_neural_corridor;
+    use approx::assert_abs_diff_eq;
+    use time::{macros::datetime, OffsetDateTime};
+
+    fn sample(hive_id: &str, minute: i64, brain: f32) -> HiveThermalSample {
+        let base_time: OffsetDateTime = datetime!(2026-02-02 18:00:00 UTC);
+        HiveThermalSample {
+            timestamp: base_time + time::Duration::minutes(minute),
+            hive_id: hive_id.to_string(),
+            ambient_temp_c: 30.0,
+            hive_internal_temp_c: 34.0,
+            wbgt_c: 28.0,
+            solar_irradiance_w_m2: 500.0,
+            relative_humidity_pct: 40.0,
+            brain_temp_approx_c: Some(brain),
+        }
+    }
+
+    #[test]
+    fn groups_unsorted_mixed_export_per_hive() {
+        let corridor = default_bee_neural_corridor();
+        let mut samples = Vec::new();
+        for m in (0..10).rev() {
+            samples.push(sample("hive-B", m, if m >= 5 { 40.5 } else { 37.0 }));
+            samples.push(sample("hive-A", m, 37.0));
+        }
+
+        let summary =
+            validate_apiary(&ApiaryCorridors::shared(corridor.clone()), &samples).unwrap();
+        assert_eq!(summary.hives.len(), 2);
+        assert_eq!(summary.hives[0].hive_id, "hive-A");
+        assert_eq!(summary.worst_hive_id, "hive-B");
+        assert_abs_diff_eq!(summary.compliant_fraction, 0.5, epsilon = 1e-6);
+
+        // Each hive matches a pre-partitioned, time-sorted batch run.
+        let hive_b: Vec<_> = (0..10)
+            .map(|m| sample("hive-B", m, if m >= 5 { 40.5 } else { 37.0 }))
+            .collect();
+        let expected = validate_hive_series(&corridor, &hive_b).unwrap();
+        assert_abs_diff_eq!(summary.hives[1].hb_score, expected.hb_score, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            summary.hb_distribution.min,
+            expected.hb_score,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn per_hive_corridor_overrides_shared() {
+        let mut relaxed = default_bee_neural_corridor();
+        relaxed.max_brain_temp_c = 41.0;
+        let corridors =
+            ApiaryCorridors::shared(default_bee_neural_corridor()).with_hive("hive-B", relaxed);
+
+        let samples: Vec<_> = (0..10).map(|m| sample("hive-B", m, 40.5)).collect();
+        let summary = validate_apiary(&corridors, &samples).unwrap();
+        assert!(summary.hives[0].violations.is_empty());
+        assert_abs_diff_eq!(summary.compliant_fraction, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn empty_export_is_error() {
+        let corridors = ApiaryCorridors::shared(default_bee_neural_corridor());
+        assert!(matches!(
+            validate_apiary(&corridors, &[]),
+            Err(ValidationError::EmptySeries)
+        ));
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use crate::HiveThermalSample;
+
+/// Steady-state heat-balance model for the bee neural temperature proxy.
+///
+/// Two bee populations are blended, weighted by `forager_fraction`:
+///
+/// - In-hive bees sit in nest air and run slightly warm:
+///   `T_in = T_hive + in_hive_excess_c`.
+/// - Exposed foragers balance metabolic and absorbed solar heat against
+///   convective and evaporative loss. Two independent readings of the same
+///   balance are fused:
+///   - heat balance: `T_hb = T_amb + metabolic_excess_c
+///     + solar_gain_c_per_w_m2 * S - evaporative_cooling_c * (1 - RH/100)`
+///   - WBGT: `T_wb = WBGT + wbgt_offset_c`, since WBGT already integrates
+///     radiant and humid load
+///   - fused: `T_out = (1 - wbgt_weight) * T_hb + wbgt_weight * T_wb`
+///
+/// `T_brain = forager_fraction * T_out + (1 - forager_fraction) * T_in`.
+///
+/// The 1-sigma uncertainty combines `base_sigma_c` in quadrature with half the
+/// disagreement between `T_hb` and `T_wb`. When the two exposed-bee readings
+/// disagree, the estimate is less trustworthy.
+///
+/// Defaults are conservative starting points and should be calibrated against
+/// thermographic field data, always in the direction of more bee protection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrainTempModel {
+    /// Share (0–1) of the estimate driven by exposed foragers.
+    pub forager_fraction: f32,
+    /// Thorax excess over nest air for in-hive bees (°C).
+    pub in_hive_excess_c: f32,
+    /// Thorax excess over ambient air from flight metabolism (°C).
+    pub metabolic_excess_c: f32,
+    /// Thorax warming per unit solar irradiance (°C per W/m²).
+    pub solar_gain_c_per_w_m2: f32,
+    /// Evaporative cooling at 0 % relative humidity (°C); scales to 0 at 100 %.
+    pub evaporative_cooling_c: f32,
+    /// Thorax excess over WBGT for exposed foragers (°C).
+    pub wbgt_offset_c: f32,
+    /// Weight (0–1) of the WBGT reading in the exposed-bee estimate.
+    pub wbgt_weight: f32,
+    /// Irreducible model uncertainty, 1-sigma (°C).
+    pub base_sigma_c: f32,
+}
+
+impl Default for BrainTempModel {
+    fn default() -> Self {
+        Self {
+            forager_fraction: 0.3,
+            in_hive_excess_c: 1.5,
+            metabolic_excess_c: 4.0,
+            solar_gain_c_per_w_m2: 0.004, // +4 °C at 1000 W/m²
+            evaporative_cooling_c: 3.0,
+            wbgt_offset_c: 6.0,
+            wbgt_weight: 0.5,
+            base_sigma_c: 1.0,
+        }
+    }
+}
+
+/// Estimated neural temperature proxy with its 1-sigma uncertainty.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BrainTempEstimate {
+    pub brain_temp_c: f32,
+    pub sigma_c: f32,
+}
+
+impl BrainTempModel {
+    /// Estimate the neural temperature proxy from a sample's environmental fields.
+    /// `brain_temp_approx_c` on the sample is ignored.
+    pub fn estimate(&self, sample: &HiveThermalSample) -> BrainTempEstimate {
+        let dryness = 1.0 - (sample.relative_humidity_pct / 100.0).clamp(0.0, 1.0);
+        let solar = sample.solar_irradiance_w_m2.max(0.0);
+
+        let t_heat_balance =
+            sample.ambient_temp_c + self.metabolic_excess_c + self.solar_gain_c_per_w_m2 * solar
+                - self.evaporative_cooling_c * dryness;
+        let t_wbgt = sample.wbgt_c + self.wbgt_offset_c;
+
+        let wbgt_weight = self.wbgt_weight.clamp(0.0, 1.0);
+        let t_out = (1.0 - wbgt_weight) * t_heat_balance + wbgt_weight * t_wbgt;
+        let t_in = sample.hive_internal_temp_c + self.in_hive_excess_c;
+
+        let forager_fraction = self.forager_fraction.clamp(0.0, 1.0);
+        let brain_temp_c = forager_fraction * t_out + (1.0 - forager_fraction) * t_in;
+
+        let disagreement = 0.5 * (t_heat_balance - t_wbgt).abs();
+        let sigma_c = (self.base_sigma_c.powi(2) + disagreement.powi(2)).sqrt();
+
+        BrainTempEstimate {
+            brain_temp_c,
+            sigma_c,
+        }
+    }
+}
+
+/// Fill `brain_temp_approx_c` on samples where it is missing (`None` or
+/// non-finite).
+///
+/// Returns the number of samples filled. Measured values are left untouched,
+/// and a sample whose estimate is not finite (e.g. a NaN environmental input)
+/// stays `None` and is not counted.
+pub fn fill_missing_brain_temp(model: &BrainTempModel, samples: &mut [HiveThermalSample]) -> usize {
+    let mut filled = 0;
+    for sample in samples
+        .iter_mut()
+        .filter(|s| !s.brain_temp_approx_c.is_some_and(f32::is_finite))
+    {
+        let estimate = model.estimate(sample).brain_temp_c;
+        sample.brain_temp_approx_c = Some(estimate).filter(|t| t.is_finite());
+        if sample.brain_temp_approx_c.is_some() {
+            filled += 1;
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use time::macros::datetime;
+
+    fn sample(brain: Option<f32>) -> HiveThermalSample {
+        HiveThermalSample {
+            timestamp: datetime!(2026-02-02 18:00:00 UTC),
+            hive_id: "hive-001".to_string(),
+            ambient_temp_c: 30.0,
+            hive_internal_temp_c: 34.0,
+            wbgt_c: 28.0,
+            solar_irradiance_w_m2: 500.0,
+            relative_humidity_pct: 40.0,
+            brain_temp_approx_c: brain,
+        }
+    }
+
+    #[test]
+    fn estimate_follows_documented_heat_balance() {
+        let model = BrainTempModel::default();
+        let est = model.estimate(&sample(None));
+
+        // T_hb = 30 + 4 + 2 - 1.8 = 34.2, T_wb = 34.0, T_out = 34.1, T_in = 35.5.
+        assert_abs_diff_eq!(est.brain_temp_c, 0.3 * 34.1 + 0.7 * 35.5, epsilon = 1e-4);
+        assert_abs_diff_eq!(est.sigma_c, (1.0f32 + 0.01).sqrt(), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn hotter_sunnier_drier_conditions_raise_estimate() {
+        let model = BrainTempModel::default();
+        let base = model.estimate(&sample(None)).brain_temp_c;
+
+        let mut hot = sample(None);
+        hot.ambient_temp_c += 5.0;
+        hot.solar_irradiance_w_m2 += 400.0;
+        hot.wbgt_c += 3.0;
+        assert!(model.estimate(&hot).brain_temp_c > base);
+
+        let mut humid = sample(None);
+        humid.relative_humidity_pct = 90.0;
+        assert!(model.estimate(&humid).brain_temp_c > base);
+    }
+
+    #[test]
+    fn fill_only_touches_missing_values() {
+        let model = BrainTempModel::default();
+        let mut samples = vec![
+            sample(Some(37.0)),
+            sample(None),
+            sample(Some(f32::INFINITY)),
+        ];
+        assert_eq!(fill_missing_brain_temp(&model, &mut samples), 2);
+        assert_eq!(samples[0].brain_temp_approx_c, Some(37.0));
+        assert!(samples
+            .iter()
+            .all(|s| s.brain_temp_approx_c.is_some_and(f32::is_finite)));
+
+        // Without a finite estimate the reading stays unknown.
+        let mut broken = vec![sample(None), sample(Some(f32::NAN))];
+        broken[0].ambient_temp_c = f32::NAN;
+        broken[1].wbgt_c = f32::NAN;
+        assert_eq!(fill_missing_brain_temp(&model, &mut broken), 0);
+        assert!(broken.iter().all(|s| s.brain_temp_approx_c.is_none()));
+    }
+}
@@ -0,0 +1,669 @@
+//! C ABI for the `cdylib` build, for C# (P/Invoke) and other foreign callers.
+//!
+//! Ownership: every `*_new`, `*_from_json` and `*_snapshot`/`*_validate_*`
+//! call hands out a pointer that the caller must release with the matching
+//! `*_free`. Strings returned by this module are freed with
+//! [`hive_string_free`]. Every fallible call returns a [`HiveStatus`]; on
+//! failure [`hive_last_error_message`] describes the error on the calling thread.
+//!
+//! The C header `include/hive_thermal_corridor_validator.h` is generated from
+//! this module with `cbindgen --config cbindgen.toml`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use time::OffsetDateTime;
+
+use crate::{
+    default_bee_neural_corridor, HiveThermalCorridor, HiveThermalSample,
+    HiveThermalStreamValidator, HiveThermalValidationResult, ValidationError, ViolationKind,
+};
+
+/// Status code returned by every fallible FFI call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HiveStatus {
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// A JSON argument could not be parsed.
+    InvalidJson = 3,
+    /// A numeric argument (e.g., a timestamp or index) was out of range.
+    InvalidArgument = 4,
+    /// [`ValidationError::EmptySeries`].
+    EmptySeries = 5,
+    /// [`ValidationError::MixedHiveIds`].
+    MixedHiveIds = 6,
+    /// [`ValidationError::InsufficientHistory`].
+    InsufficientHistory = 7,
+    /// The validator panicked; the handle should be freed and not reused.
+    Panic = 8,
+}
+
+impl From<&ValidationError> for HiveStatus {
+    fn from(err: &ValidationError) -> Self {
+        match err {
+            ValidationError::EmptySeries => HiveStatus::EmptySeries,
+            ValidationError::MixedHiveIds { .. } => HiveStatus::MixedHiveIds,
+            ValidationError::InsufficientHistory { .. } => HiveStatus::InsufficientHistory,
+            ValidationError::InvalidSchedule(_) => HiveStatus::InvalidArgument,
+        }
+    }
+}
+
+/// Violation kind as seen from C.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HiveViolationKind {
+    HiveInternalOverheat = 0,
+    BeeBrainOverheat = 1,
+    HiveWbgtOverheat = 2,
+    CooldownTooSlow = 3,
+    HiveInternalChill = 4,
+    HumidityTooLow = 5,
+    HumidityTooHigh = 6,
+    WarmupTooSlow = 7,
+    SustainedExceedance = 8,
+}
+
+impl From<&ViolationKind> for HiveViolationKind {
+    fn from(kind: &ViolationKind) -> Self {
+        match kind {
+            ViolationKind::HiveInternalOverheat => HiveViolationKind::HiveInternalOverheat,
+            ViolationKind::BeeBrainOverheat => HiveViolationKind::BeeBrainOverheat,
+            ViolationKind::HiveWbgtOverheat => HiveViolationKind::HiveWbgtOverheat,
+            ViolationKind::CooldownTooSlow => HiveViolationKind::CooldownTooSlow,
+            ViolationKind::HiveInternalChill => HiveViolationKind::HiveInternalChill,
+            ViolationKind::HumidityTooLow => HiveViolationKind::HumidityTooLow,
+            ViolationKind::HumidityTooHigh => HiveViolationKind::HumidityTooHigh,
+            ViolationKind::WarmupTooSlow => HiveViolationKind::WarmupTooSlow,
+            ViolationKind::SustainedExceedance { .. } => HiveViolationKind::SustainedExceedance,
+        }
+    }
+}
+
+/// One sample passed by value from C. `brain_temp_approx_c` is NaN when not
+/// yet estimated; any non-finite value is read as missing.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HiveSampleC {
+    /// Milliseconds since the Unix epoch (UTC).
+    pub timestamp_unix_ms: i64,
+    /// NUL-terminated UTF-8 hive identifier.
+    pub hive_id: *const c_char,
+    pub ambient_temp_c: f32,
+    pub hive_internal_temp_c: f32,
+    pub wbgt_c: f32,
+    pub solar_irradiance_w_m2: f32,
+    pub relative_humidity_pct: f32,
+    pub brain_temp_approx_c: f32,
+}
+
+/// Headline numbers of a validation result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HiveResultSummaryC {
+    pub total_samples: u64,
+    pub violation_count: u64,
+    pub safe_fraction: f32,
+    pub data_coverage_fraction: f32,
+    pub hb_score: f32,
+    pub is_beesafe_compliant: bool,
+}
+
+/// One violation, flattened. Sustained-exceedance details are only available
+/// through [`hive_result_to_json`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HiveViolationC {
+    pub timestamp_unix_ms: i64,
+    pub kind: HiveViolationKind,
+    pub value: f32,
+    pub threshold: f32,
+}
+
+/// Opaque corridor handle.
+pub struct HiveCorridor(HiveThermalCorridor);
+
+/// Opaque streaming validator handle.
+pub struct HiveValidator(HiveThermalStreamValidator);
+
+/// Opaque validation result handle.
+pub struct HiveResult(HiveThermalValidationResult);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: impl Into<String>) {
+    // Interior NULs would truncate the message in C; replace them.
+    let message = message.into().replace('\0', " ");
+    let message = CString::new(message).expect("NULs were replaced");
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+fn fail(status: HiveStatus, message: impl Into<String>) -> HiveStatus {
+    set_last_error(message);
+    status
+}
+
+fn validation_failure(err: &ValidationError) -> HiveStatus {
+    fail(err.into(), err.to_string())
+}
+
+/// Run `f`, converting a panic into [`HiveStatus::Panic`] so it never unwinds into C.
+fn guarded(f: impl FnOnce() -> HiveStatus) -> HiveStatus {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| fail(HiveStatus::Panic, "panic inside hive validator"))
+}
+
+unsafe fn read_str<'a>(s: *const c_char, what: &str) -> Result<&'a str, HiveStatus> {
+    if s.is_null() {
+        return Err(fail(HiveStatus::NullPointer, format!("{what} is null")));
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| {
+        fail(
+            HiveStatus::InvalidUtf8,
+            format!("{what} is not valid UTF-8"),
+        )
+    })
+}
+
+unsafe fn parse_json<T: serde::de::DeserializeOwned>(
+    json: *const c_char,
+    what: &str,
+) -> Result<T, HiveStatus> {
+    let text = read_str(json, what)?;
+    serde_json::from_str(text)
+        .map_err(|e| fail(HiveStatus::InvalidJson, format!("invalid {what}: {e}")))
+}
+
+fn unix_ms(t: OffsetDateTime) -> i64 {
+    (t.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+unsafe fn sample_from_c(sample: &HiveSampleC) -> Result<HiveThermalSample, HiveStatus> {
+    let hive_id = read_str(sample.hive_id, "hive_id")?.to_string();
+    let timestamp =
+        OffsetDateTime::from_unix_timestamp_nanos(sample.timestamp_unix_ms as i128 * 1_000_000)
+            .map_err(|e| fail(HiveStatus::InvalidArgument, format!("timestamp: {e}")))?;
+    Ok(HiveThermalSample {
+        timestamp,
+        hive_id,
+        ambient_temp_c: sample.ambient_temp_c,
+        hive_internal_temp_c: sample.hive_internal_temp_c,
+        wbgt_c: sample.wbgt_c,
+        solar_irradiance_w_m2: sample.solar_irradiance_w_m2,
+        relative_humidity_pct: sample.relative_humidity_pct,
+        brain_temp_approx_c: Some(sample.brain_temp_approx_c).filter(|t| t.is_finite()),
+    })
+}
+
+macro_rules! non_null {
+    ($ptr:expr, $name:literal) => {
+        if $ptr.is_null() {
+            return fail(HiveStatus::NullPointer, concat!($name, " is null"));
+        }
+    };
+}
+
+/// NUL-terminated library version string; statically allocated, do not free.
+#[no_mangle]
+pub extern "C" fn hive_validator_version() -> *const c_char {
+    c"hive_thermal_corridor_validator_v0.1.0".as_ptr()
+}
+
+/// Message for the last failed call on this thread, or null if none. Valid
+/// until the next failing call on the same thread; do not free.
+#[no_mangle]
+pub extern "C" fn hive_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// New corridor with the default bee neural-safety limits.
+#[no_mangle]
+pub extern "C" fn hive_corridor_default() -> *mut HiveCorridor {
+    Box::into_raw(Box::new(HiveCorridor(default_bee_neural_corridor())))
+}
+
+/// Parse a corridor from JSON (the serde form of [`HiveThermalCorridor`]).
+///
+/// # Safety
+/// `json` must be a valid NUL-terminated string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_corridor_from_json(
+    json: *const c_char,
+    out: *mut *mut HiveCorridor,
+) -> HiveStatus {
+    non_null!(out, "out");
+    guarded(|| match parse_json(json, "corridor JSON") {
+        Ok(corridor) => {
+            *out = Box::into_raw(Box::new(HiveCorridor(corridor)));
+            HiveStatus::Ok
+        }
+        Err(status) => status,
+    })
+}
+
+/// # Safety
+/// `corridor` must be null or a pointer returned by this library, freed once.
+#[no_mangle]
+pub unsafe extern "C" fn hive_corridor_free(corridor: *mut HiveCorridor) {
+    if !corridor.is_null() {
+        drop(Box::from_raw(corridor));
+    }
+}
+
+/// New streaming validator over a copy of `corridor`; null if `corridor` is null.
+///
+/// # Safety
+/// `corridor` must be null or a live corridor handle.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_new(corridor: *const HiveCorridor) -> *mut HiveValidator {
+    match corridor.as_ref() {
+        Some(c) => Box::into_raw(Box::new(HiveValidator(HiveThermalStreamValidator::new(
+            c.0.clone(),
+        )))),
+        None => {
+            set_last_error("corridor is null");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Push one sample. On error the validator state is unchanged.
+///
+/// # Safety
+/// `validator` must be a live validator handle and `sample` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_push(
+    validator: *mut HiveValidator,
+    sample: *const HiveSampleC,
+) -> HiveStatus {
+    non_null!(validator, "validator");
+    non_null!(sample, "sample");
+    guarded(|| {
+        let sample = match sample_from_c(&*sample) {
+            Ok(s) => s,
+            Err(status) => return status,
+        };
+        match (*validator).0.push(&sample) {
+            Ok(()) => HiveStatus::Ok,
+            Err(e) => validation_failure(&e),
+        }
+    })
+}
+
+/// Push a contiguous array of `len` samples, stopping at the first error.
+/// `pushed` (optional) receives the number of samples accepted.
+///
+/// # Safety
+/// `samples` must point to `len` valid samples; `pushed` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_push_array(
+    validator: *mut HiveValidator,
+    samples: *const HiveSampleC,
+    len: usize,
+    pushed: *mut usize,
+) -> HiveStatus {
+    non_null!(validator, "validator");
+    if len > 0 {
+        non_null!(samples, "samples");
+    }
+    let mut accepted = 0;
+    let status = guarded(|| {
+        for sample in std::slice::from_raw_parts(samples, len) {
+            let status = hive_validator_push(validator, sample);
+            if status != HiveStatus::Ok {
+                return status;
+            }
+            accepted += 1;
+        }
+        HiveStatus::Ok
+    });
+    if let Some(p) = pushed.as_mut() {
+        *p = accepted;
+    }
+    status
+}
+
+/// Push a JSON array of samples (the serde form of [`HiveThermalSample`]),
+/// stopping at the first error.
+///
+/// # Safety
+/// `validator` must be a live validator handle and `json` a valid string.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_push_json(
+    validator: *mut HiveValidator,
+    json: *const c_char,
+) -> HiveStatus {
+    non_null!(validator, "validator");
+    guarded(|| {
+        let samples: Vec<HiveThermalSample> = match parse_json(json, "samples JSON") {
+            Ok(s) => s,
+            Err(status) => return status,
+        };
+        for sample in &samples {
+            if let Err(e) = (*validator).0.push(sample) {
+                return validation_failure(&e);
+            }
+        }
+        HiveStatus::Ok
+    })
+}
+
+/// Result for everything pushed so far; the validator stays usable.
+///
+/// # Safety
+/// `validator` must be a live validator handle and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_snapshot(
+    validator: *const HiveValidator,
+    out: *mut *mut HiveResult,
+) -> HiveStatus {
+    non_null!(validator, "validator");
+    non_null!(out, "out");
+    guarded(|| match (*validator).0.snapshot() {
+        Ok(result) => {
+            *out = Box::into_raw(Box::new(HiveResult(result)));
+            HiveStatus::Ok
+        }
+        Err(e) => validation_failure(&e),
+    })
+}
+
+/// # Safety
+/// `validator` must be null or a pointer returned by this library, freed once.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validator_free(validator: *mut HiveValidator) {
+    if !validator.is_null() {
+        drop(Box::from_raw(validator));
+    }
+}
+
+/// One-shot batch validation of a JSON sample array, as
+/// [`validate_hive_series`](crate::validate_hive_series).
+///
+/// # Safety
+/// `corridor` must be a live corridor handle, `samples_json` a valid string
+/// and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_validate_series_json(
+    corridor: *const HiveCorridor,
+    samples_json: *const c_char,
+    out: *mut *mut HiveResult,
+) -> HiveStatus {
+    non_null!(corridor, "corridor");
+    non_null!(out, "out");
+    guarded(|| {
+        let samples: Vec<HiveThermalSample> = match parse_json(samples_json, "samples JSON") {
+            Ok(s) => s,
+            Err(status) => return status,
+        };
+        match crate::validate_hive_series(&(*corridor).0, &samples) {
+            Ok(result) => {
+                *out = Box::into_raw(Box::new(HiveResult(result)));
+                HiveStatus::Ok
+            }
+            Err(e) => validation_failure(&e),
+        }
+    })
+}
+
+/// # Safety
+/// `result` must be a live result handle and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_result_summary(
+    result: *const HiveResult,
+    out: *mut HiveResultSummaryC,
+) -> HiveStatus {
+    non_null!(result, "result");
+    non_null!(out, "out");
+    let r = &(*result).0;
+    *out = HiveResultSummaryC {
+        total_samples: r.total_samples as u64,
+        violation_count: r.violations.len() as u64,
+        safe_fraction: r.safe_fraction,
+        data_coverage_fraction: r.data_coverage_fraction,
+        hb_score: r.hb_score,
+        is_beesafe_compliant: r.is_beesafe_compliant,
+    };
+    HiveStatus::Ok
+}
+
+/// Violation at `index` (0-based, below `violation_count`).
+///
+/// # Safety
+/// `result` must be a live result handle and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hive_result_violation(
+    result: *const HiveResult,
+    index: usize,
+    out: *mut HiveViolationC,
+) -> HiveStatus {
+    non_null!(result, "result");
+    non_null!(out, "out");
+    let violations = &(*result).0.violations;
+    let Some(v) = violations.get(index) else {
+        return fail(
+            HiveStatus::InvalidArgument,
+            format!(
+                "violation index {index} out of range ({})",
+                violations.len()
+            ),
+        );
+    };
+    *out = HiveViolationC {
+        timestamp_unix_ms: unix_ms(v.timestamp),
+        kind: (&v.kind).into(),
+        value: v.value,
+        threshold: v.threshold,
+    };
+    HiveStatus::Ok
+}
+
+/// Full result as JSON, including warnings, breakdown and exposure. Free the
+/// returned string with [`hive_string_free`]; null on failure.
+///
+/// # Safety
+/// `result` must be a live result handle.
+#[no_mangle]
+pub unsafe extern "C" fn hive_result_to_json(result: *const HiveResult) -> *mut c_char {
+    let Some(r) = result.as_ref() else {
+        set_last_error("result is null");
+        return ptr::null_mut();
+    };
+    let json = match serde_json::to_string(&r.0) {
+        Ok(json) => json,
+        Err(e) => {
+            set_last_error(format!("serializing result: {e}"));
+            return ptr::null_mut();
+        }
+    };
+    match CString::new(json) {
+        Ok(json) => json.into_raw(),
+        Err(e) => {
+            set_last_error(format!("result JSON contains a NUL byte: {e}"));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// # Safety
+/// `result` must be null or a pointer returned by this library, freed once.
+#[no_mangle]
+pub unsafe extern "C" fn hive_result_free(result: *mut HiveResult) {
+    if !result.is_null() {
+        drop(Box::from_raw(result));
+    }
+}
+
+/// # Safety
+/// `s` must be null or a string returned by this library, freed once.
+#[no_mangle]
+pub unsafe extern "C" fn hive_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c_sample(hive_id: &CStr, minute: i64, hive_internal: f32) -> HiveSampleC {
+        HiveSampleC {
+            timestamp_unix_ms: 1_770_055_200_000 + minute * 60_000, // 2026-02-02 18:00 UTC
+            hive_id: hive_id.as_ptr(),
+            ambient_temp_c: 30.0,
+            hive_internal_temp_c: hive_internal,
+            wbgt_c: 28.0,
+            solar_irradiance_w_m2: 500.0,
+            relative_humidity_pct: 40.0,
+            brain_temp_approx_c: 37.0,
+        }
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(hive_last_error_message()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn stream_round_trip_through_c_abi() {
+        unsafe {
+            let corridor = hive_corridor_default();
+            let validator = hive_validator_new(corridor);
+            assert!(!validator.is_null());
+
+            let temps = [34.0, 34.0, 36.0, 34.0, 34.0];
+            let samples: Vec<_> = temps
+                .iter()
+                .enumerate()
+                .map(|(i, &t)| c_sample(c"hive-ffi", i as i64, t))
+                .collect();
+            let mut pushed = 0;
+            assert_eq!(
+                hive_validator_push_array(validator, samples.as_ptr(), samples.len(), &mut pushed),
+                HiveStatus::Ok
+            );
+            assert_eq!(pushed, 5);
+
+            let mut result = ptr::null_mut();
+            assert_eq!(
+                hive_validator_snapshot(validator, &mut result),
+                HiveStatus::Ok
+            );
+            let mut summary = HiveResultSummaryC::default();
+            assert_eq!(hive_result_summary(result, &mut summary), HiveStatus::Ok);
+            assert_eq!(summary.total_samples, 5);
+            assert_eq!(summary.violation_count, 1);
+
+            let mut v = HiveViolationC {
+                timestamp_unix_ms: 0,
+                kind: HiveViolationKind::WarmupTooSlow,
+                value: 0.0,
+                threshold: 0.0,
+            };
+            assert_eq!(hive_result_violation(result, 0, &mut v), HiveStatus::Ok);
+            assert_eq!(v.kind, HiveViolationKind::HiveInternalOverheat);
+            assert_eq!(v.timestamp_unix_ms, samples[2].timestamp_unix_ms);
+            assert_eq!(
+                hive_result_violation(result, 1, &mut v),
+                HiveStatus::InvalidArgument
+            );
+
+            let json = hive_result_to_json(result);
+            let parsed: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert_eq!(parsed["hive_id"], "hive-ffi");
+            hive_string_free(json);
+
+            hive_result_free(result);
+            hive_validator_free(validator);
+            hive_corridor_free(corridor);
+        }
+    }
+
+    #[test]
+    fn validation_errors_map_to_status_codes() {
+        unsafe {
+            let corridor = hive_corridor_default();
+            let validator = hive_validator_new(corridor);
+
+            let mut result = ptr::null_mut();
+            assert_eq!(
+                hive_validator_snapshot(validator, &mut result),
+                HiveStatus::EmptySeries
+            );
+            assert_eq!(last_error(), "no samples provided");
+
+            assert_eq!(
+                hive_validator_push(validator, &c_sample(c"hive-a", 0, 34.0)),
+                HiveStatus::Ok
+            );
+            assert_eq!(
+                hive_validator_push(validator, &c_sample(c"hive-b", 1, 34.0)),
+                HiveStatus::MixedHiveIds
+            );
+
+            assert_eq!(
+                hive_validate_series_json(corridor, c"[".as_ptr(), &mut result),
+                HiveStatus::InvalidJson
+            );
+            assert_eq!(
+                hive_validate_series_json(corridor, c"[]".as_ptr(), &mut result),
+                HiveStatus::EmptySeries
+            );
+            assert_eq!(
+                hive_validator_push(validator, ptr::null()),
+                HiveStatus::NullPointer
+            );
+            assert!(result.is_null());
+
+            hive_validator_free(validator);
+            hive_corridor_free(corridor);
+        }
+    }
+
+    #[test]
+    fn corridor_and_samples_accept_serde_json() {
+        let corridor_json =
+            CString::new(serde_json::to_string(&default_bee_neural_corridor()).unwrap()).unwrap();
+        let sample = HiveThermalSample {
+            timestamp: time::macros::datetime!(2026-02-02 18:00 UTC),
+            hive_id: "hive-json".to_string(),
+            ambient_temp_c: 30.0,
+            hive_internal_temp_c: 34.0,
+            wbgt_c: 28.0,
+            solar_irradiance_w_m2: 500.0,
+            relative_humidity_pct: 40.0,
+            brain_temp_approx_c: Some(37.0),
+        };
+        let samples_json = CString::new(serde_json::to_string(&[sample]).unwrap()).unwrap();
+        unsafe {
+            let mut corridor = ptr::null_mut();
+            assert_eq!(
+                hive_corridor_from_json(corridor_json.as_ptr(), &mut corridor),
+                HiveStatus::Ok
+            );
+            let mut result = ptr::null_mut();
+            assert_eq!(
+                hive_validate_series_json(corridor, samples_json.as_ptr(), &mut result),
+                HiveStatus::Ok
+            );
+            let mut summary = HiveResultSummaryC::default();
+            hive_result_summary(result, &mut summary);
+            assert_eq!(summary.total_samples, 1);
+            assert!(summary.is_beesafe_compliant);
+
+            hive_result_free(result);
+            hive_corridor_free(corridor);
+        }
+    }
+}
@@ -0,0 +1,479 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{HiveThermalCorridor, HiveThermalSample, ThermalLimit, ValidationError};
+
+/// Settings for the short-horizon corridor breach predictor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastConfig {
+    /// History window (s) before the latest sample used to fit the model.
+    pub lookback_s: u64,
+    /// How far ahead (s) to look for a breach.
+    pub horizon_s: u64,
+    /// Resolution (s) of the breach search within the horizon.
+    pub step_s: u64,
+    /// Minimum samples in the lookback window needed to fit a limit.
+    pub min_samples: usize,
+    /// Ridge penalty on the standardized regressors; keeps the fit stable when
+    /// time, ambient and solar are collinear (as they are on a clear morning).
+    pub ridge_lambda: f64,
+}
+
+impl Default for ForecastConfig {
+    fn default() -> Self {
+        Self {
+            lookback_s: 1800, // 30 minutes
+            horizon_s: 3600,  // 1 hour
+            step_s: 60,
+            min_samples: 5,
+            ridge_lambda: 0.1,
+        }
+    }
+}
+
+/// Predicted trajectory of one corridor limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitForecast {
+    pub limit: ThermalLimit,
+    /// Latest measured reading.
+    pub current_value: f32,
+    pub threshold: f32,
+    /// Model prediction at the end of the horizon.
+    pub predicted_at_horizon: f32,
+    /// Seconds from the latest sample until the predicted reading crosses the
+    /// limit; `Some(0)` if already beyond it, `None` if not within the horizon.
+    pub time_to_breach_s: Option<u64>,
+}
+
+/// Early warning that a limit is predicted to be crossed within the horizon,
+/// so shading or ventilation can be deployed before the corridor is breached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreViolationWarning {
+    pub hive_id: String,
+    /// Timestamp of the latest sample the forecast was made from.
+    pub issued_at: OffsetDateTime,
+    pub limit: ThermalLimit,
+    pub expected_breach_at: OffsetDateTime,
+    pub time_to_breach_s: u64,
+    pub current_value: f32,
+    pub threshold: f32,
+}
+
+/// Forecast every limit the corridor defines from a time-ordered single-hive history.
+///
+/// Each reading is fitted by ridge least squares on elapsed time, ambient
+/// temperature and solar irradiance over the lookback window. Ambient and
+/// solar are extrapolated by their own linear trends (solar floored at 0),
+/// and the fitted model is stepped across the horizon to find the first
+/// crossing, refined by linear interpolation. Samples whose ambient or solar
+/// reading is not finite are left out of every fit, and the window must keep
+/// `min_samples` of them. Limits with fewer than `min_samples` finite
+/// readings in the window are omitted.
+pub fn forecast_limits(
+    corridor: &HiveThermalCorridor,
+    config: &ForecastConfig,
+    history: &[HiveThermalSample],
+) -> Result<Vec<LimitForecast>, ValidationError> {
+    let window = lookback_window(config, history)?;
+    let last = window[window.len() - 1];
+
+    let minutes: Vec<f64> = window
+        .iter()
+        .map(|s| (s.timestamp - last.timestamp).as_seconds_f64() / 60.0)
+        .collect();
+    let (driver_minutes, driver_samples): (Vec<f64>, Vec<_>) = minutes
+        .iter()
+        .zip(&window)
+        .filter(|(_, s)| has_finite_drivers(s))
+        .map(|(&tau, &s)| (tau, s))
+        .unzip();
+    let ambient = LinearTrend::fit(
+        &driver_minutes,
+        &driver_samples
+            .iter()
+            .map(|s| s.ambient_temp_c as f64)
+            .collect::<Vec<_>>(),
+    );
+    let solar = LinearTrend::fit(
+        &driver_minutes,
+        &driver_samples
+            .iter()
+            .map(|s| s.solar_irradiance_w_m2 as f64)
+            .collect::<Vec<_>>(),
+    );
+    let drivers_at = |tau: f64| [tau, ambient.at(tau), solar.at(tau).max(0.0)];
+
+    let mut forecasts = Vec::new();
+    for limit in ThermalLimit::ALL {
+        let Some(threshold) = limit.threshold(corridor) else {
+            continue;
+        };
+
+        let mut rows = Vec::new();
+        let mut ys = Vec::new();
+        let mut latest = None;
+        for (s, &tau) in window.iter().zip(&minutes) {
+            let Some(y) = limit.reading(s) else {
+                continue;
+            };
+            latest = Some(y);
+            if has_finite_drivers(s) {
+                rows.push([tau, s.ambient_temp_c as f64, s.solar_irradiance_w_m2 as f64]);
+                ys.push(y as f64);
+            }
+        }
+        let Some(current_value) = latest else {
+            continue;
+        };
+        if ys.len() < config.min_samples {
+            continue;
+        }
+        let model = RidgeModel::fit(&rows, &ys, config.ridge_lambda);
+
+        let horizon_min = config.horizon_s as f64 / 60.0;
+        let predicted_at_horizon = model.predict(&drivers_at(horizon_min)) as f32;
+
+        let time_to_breach_s = if limit.is_breached_by(current_value, threshold) {
+            Some(0)
+        } else {
+            first_crossing(config, threshold as f64, limit.is_lower_bound(), |tau| {
+                model.predict(&drivers_at(tau))
+            })
+        };
+
+        forecasts.push(LimitForecast {
+            limit,
+            current_value,
+            threshold,
+            predicted_at_horizon,
+            time_to_breach_s,
+        });
+    }
+    Ok(forecasts)
+}
+
+/// Pre-violation warnings for limits not yet breached but predicted to be
+/// crossed within the horizon, soonest first.
+pub fn early_warnings(
+    corridor: &HiveThermalCorridor,
+    config: &ForecastConfig,
+    history: &[HiveThermalSample],
+) -> Result<Vec<PreViolationWarning>, ValidationError> {
+    let forecasts = forecast_limits(corridor, config, history)?;
+    let last = &history[history.len() - 1];
+
+    let mut warnings: Vec<_> = forecasts
+        .into_iter()
+        .filter_map(|f| {
+            let time_to_breach_s = f.time_to_breach_s.filter(|t| *t > 0)?;
+            Some(PreViolationWarning {
+                hive_id: last.hive_id.clone(),
+                issued_at: last.timestamp,
+                limit: f.limit,
+                expected_breach_at: last.timestamp
+                    + time::Duration::seconds(time_to_breach_s as i64),
+                time_to_breach_s,
+                current_value: f.current_value,
+                threshold: f.threshold,
+            })
+        })
+        .collect();
+    warnings.sort_by_key(|w| w.time_to_breach_s);
+    Ok(warnings)
+}
+
+fn lookback_window<'a>(
+    config: &ForecastConfig,
+    history: &'a [HiveThermalSample],
+) -> Result<Vec<&'a HiveThermalSample>, ValidationError> {
+    let last = history.last().ok_or(ValidationError::EmptySeries)?;
+    if let Some(s) = history.iter().find(|s| s.hive_id != last.hive_id) {
+        return Err(ValidationError::MixedHiveIds {
+            expected: last.hive_id.clone(),
+            found: s.hive_id.clone(),
+        });
+    }
+
+    let cutoff = last.timestamp - time::Duration::seconds(config.lookback_s as i64);
+    let window: Vec<_> = history.iter().filter(|s| s.timestamp >= cutoff).collect();
+    let usable = window.iter().filter(|s| has_finite_drivers(s)).count();
+    if usable < config.min_samples {
+        return Err(ValidationError::InsufficientHistory {
+            needed: config.min_samples,
+            found: usable,
+        });
+    }
+    Ok(window)
+}
+
+/// Ambient and solar are regressors; a non-finite one would poison the fit.
+fn has_finite_drivers(s: &HiveThermalSample) -> bool {
+    s.ambient_temp_c.is_finite() && s.solar_irradiance_w_m2.is_finite()
+}
+
+/// Step the prediction forward and return the first crossing time (s).
+fn first_crossing(
+    config: &ForecastConfig,
+    threshold: f64,
+    lower_bound: bool,
+    predict: impl Fn(f64) -> f64,
+) -> Option<u64> {
+    let beyond = |y: f64| {
+        if lower_bound {
+            y < threshold
+        } else {
+            y > threshold
+        }
+    };
+    let step_s = config.step_s.max(1);
+
+    let mut prev_t = 0u64;
+    let mut prev_y = predict(0.0);
+    let mut t = step_s;
+    while t <= config.horizon_s {
+        let y = predict(t as f64 / 60.0);
+        if beyond(y) {
+            if beyond(prev_y) {
+                return Some(prev_t.max(1));
+            }
+            let frac = (threshold - prev_y) / (y - prev_y);
+            let crossing = prev_t as f64 + frac.clamp(0.0, 1.0) * (t - prev_t) as f64;
+            return Some((crossing.ceil() as u64).max(1));
+        }
+        prev_t = t;
+        prev_y = y;
+        t += step_s;
+    }
+    None
+}
+
+/// Ordinary least-squares line `y = intercept + slope * x`.
+struct LinearTrend {
+    intercept: f64,
+    slope: f64,
+}
+
+impl LinearTrend {
+    fn fit(xs: &[f64], ys: &[f64]) -> Self {
+        let n = xs.len() as f64;
+        let mx = xs.iter().sum::<f64>() / n;
+        let my = ys.iter().sum::<f64>() / n;
+        let sxx: f64 = xs.iter().map(|x| (x - mx).powi(2)).sum();
+        let sxy: f64 = xs.iter().zip(ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+        let slope = if sxx > 0.0 { sxy / sxx } else { 0.0 };
+        Self {
+            intercept: my - slope * mx,
+            slope,
+        }
+    }
+
+    fn at(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// Ridge regression on standardized regressors `[minutes, ambient, solar]`.
+/// Constant regressors get a zero coefficient.
+struct RidgeModel {
+    y_mean: f64,
+    x_mean: [f64; 3],
+    x_scale: [f64; 3],
+    beta: [f64; 3],
+}
+
+impl RidgeModel {
+    fn fit(rows: &[[f64; 3]], ys: &[f64], lambda: f64) -> Self {
+        let n = ys.len() as f64;
+        let y_mean = ys.iter().sum::<f64>() / n;
+
+        let mut x_mean = [0.0; 3];
+        let mut x_scale = [0.0; 3];
+        for j in 0..3 {
+            x_mean[j] = rows.iter().map(|r| r[j]).sum::<f64>() / n;
+            let var = rows.iter().map(|r| (r[j] - x_mean[j]).powi(2)).sum::<f64>() / n;
+            x_scale[j] = var.sqrt();
+        }
+        let standardize = |r: &[f64; 3]| -> [f64; 3] {
+            std::array::from_fn(|j| {
+                if x_scale[j] > 1e-12 {
+                    (r[j] - x_mean[j]) / x_scale[j]
+                } else {
+                    0.0
+                }
+            })
+        };
+
+        // Normal equations (ZᵀZ + λI) β = Zᵀ(y - ȳ).
+        let mut a = [[0.0; 3]; 3];
+        let mut b = [0.0; 3];
+        for (r, y) in rows.iter().zip(ys) {
+            let z = standardize(r);
+            for (i, (a_row, b_i)) in a.iter_mut().zip(&mut b).enumerate() {
+                *b_i += z[i] * (y - y_mean);
+                for (a_ik, z_k) in a_row.iter_mut().zip(&z) {
+                    *a_ik += z[i] * z_k;
+                }
+            }
+        }
+        for (i, row) in a.iter_mut().enumerate() {
+            row[i] += lambda.max(1e-9);
+        }
+
+        Self {
+            y_mean,
+            x_mean,
+            x_scale,
+            beta: solve3(a, b),
+        }
+    }
+
+    fn predict(&self, x: &[f64; 3]) -> f64 {
+        let mut y = self.y_mean;
+        for (j, x_j) in x.iter().enumerate() {
+            if self.x_scale[j] > 1e-12 {
+                y += self.beta[j] * (x_j - self.x_mean[j]) / self.x_scale[j];
+            }
+        }
+        y
+    }
+}
+
+/// Gaussian elimination with partial pivoting for a 3×3 system.
+fn solve3(mut a: [[f64; 3]; 3], mut b: [f64; 3]) -> [f64; 3] {
+    for col in 0..3 {
+        let pivot = (col..3)
+            .max_by(|&i, &k| a[i][col].abs().total_cmp(&a[k][col].abs()))
+            .unwrap_or(col);
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        if a[col][col].abs() < 1e-12 {
+            continue;
+        }
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let f = row[col] / pivot_row[col];
+            for (r_k, p_k) in row.iter_mut().zip(pivot_row).skip(col) {
+                *r_k -= f * p_k;
+            }
+            b[col + 1 + offset] -= f * b[col];
+        }
+    }
+
+    let mut x = [0.0; 3];
+    for row in (0..3).rev() {
+        if a[row][row].abs() < 1e-12 {
+            continue;
+        }
+        let tail: f64 = (row + 1..3).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default_bee_neural_corridor;
+    use time::macros::datetime;
+
+    /// Morning warm-up: hive internal rises 0.1 °C/min from 33 °C while ambient
+    /// and solar climb; everything else is flat and safe.
+    fn warming_series(minutes: i64) -> Vec<HiveThermalSample> {
+        (0..minutes)
+            .map(|m| HiveThermalSample {
+                timestamp: datetime!(2026-06-01 16:00 UTC) + time::Duration::minutes(m),
+                hive_id: "hive-013".to_string(),
+                ambient_temp_c: 30.0 + 0.05 * m as f32,
+                hive_internal_temp_c: 33.0 + 0.1 * m as f32,
+                wbgt_c: 26.0,
+                solar_irradiance_w_m2: 400.0 + 5.0 * m as f32,
+                relative_humidity_pct: 40.0,
+                brain_temp_approx_c: Some(36.0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn linear_warming_predicts_time_to_breach() {
+        let corridor = default_bee_neural_corridor();
+        // Last sample at minute 9 reads 33.9 °C; 35 °C is 11 minutes away.
+        let history = warming_series(10);
+        let forecasts = forecast_limits(&corridor, &ForecastConfig::default(), &history).unwrap();
+
+        let hive = forecasts
+            .iter()
+            .find(|f| f.limit == ThermalLimit::HiveInternal)
+            .unwrap();
+        let ttb = hive.time_to_breach_s.unwrap();
+        assert!((650..=670).contains(&ttb), "time to breach {ttb}");
+
+        let wbgt = forecasts
+            .iter()
+            .find(|f| f.limit == ThermalLimit::HiveWbgt)
+            .unwrap();
+        assert_eq!(wbgt.time_to_breach_s, None);
+    }
+
+    #[test]
+    fn early_warning_only_for_limits_not_yet_breached() {
+        let corridor = default_bee_neural_corridor();
+        let config = ForecastConfig::default();
+
+        let warnings = early_warnings(&corridor, &config, &warming_series(10)).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].limit, ThermalLimit::HiveInternal);
+        assert_eq!(
+            warnings[0].expected_breach_at,
+            warnings[0].issued_at + time::Duration::seconds(warnings[0].time_to_breach_s as i64)
+        );
+
+        // Once past 35 °C the breach is a violation, not a warning.
+        assert!(early_warnings(&corridor, &config, &warming_series(25))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn broken_driver_readings_do_not_silence_warnings() {
+        let corridor = default_bee_neural_corridor();
+        let config = ForecastConfig::default();
+        let mut history = warming_series(10);
+        history[4].ambient_temp_c = f32::NAN;
+        history[7].solar_irradiance_w_m2 = f32::INFINITY;
+
+        let warnings = early_warnings(&corridor, &config, &history).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].limit, ThermalLimit::HiveInternal);
+
+        for s in &mut history[3..] {
+            s.ambient_temp_c = f32::NAN;
+        }
+        assert!(matches!(
+            forecast_limits(&corridor, &config, &history),
+            Err(ValidationError::InsufficientHistory {
+                needed: 5,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn forecast_is_deterministic_and_needs_history() {
+        let corridor = default_bee_neural_corridor();
+        let config = ForecastConfig::default();
+        let history = warming_series(10);
+
+        let a = serde_json::to_value(forecast_limits(&corridor, &config, &history).unwrap());
+        let b = serde_json::to_value(forecast_limits(&corridor, &config, &history).unwrap());
+        assert_eq!(a.unwrap(), b.unwrap());
+
+        assert!(matches!(
+            forecast_limits(&corridor, &config, &history[..3]),
+            Err(ValidationError::InsufficientHistory {
+                needed: 5,
+                found: 3
+            })
+        ));
+    }
+}
@@ -1,6 +1,31 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use time::{OffsetDateTime, macros::datetime};
+use time::OffsetDateTime;
+
+pub mod apiary;
+pub mod brain_temp;
+pub mod ffi;
+pub mod forecast;
+pub mod ingest;
+pub mod report;
+pub mod schedule;
+pub mod scoring;
+
+pub use apiary::{validate_apiary, ApiaryCorridors, ApiaryValidationSummary};
+pub use brain_temp::{fill_missing_brain_temp, BrainTempEstimate, BrainTempModel};
+pub use forecast::{
+    early_warnings, forecast_limits, ForecastConfig, LimitForecast, PreViolationWarning,
+};
+pub use ingest::{
+    read_csv, read_jsonl, ColumnMapping, IngestConfig, IngestError, IngestReport, RowError,
+};
+pub use report::{ComplianceReport, REPORT_SCHEMA_VERSION};
+pub use schedule::{
+    validate_hive_series_scheduled, ColonyState, CorridorSchedule, InvalidSeasonDate, ScheduleError,
+};
+pub use scoring::{HbContribution, HbDimension, HbMethod, HbScoringModel};
+
+use scoring::ScoringInput;
 
 /// Honey-bee and hive thermal safety corridors are enforced as hard constraints,
 /// not soft preferences, consistent with your corridor grammar and Lyapunov-style safety logic.[file:3][file:10]
@@ -10,7 +35,12 @@ use time::{OffsetDateTime, macros::datetime};
 /// All temperatures are in degrees Celsius.
 /// wbgt is the local Wet Bulb Globe Temperature at hive height (~1 m).
 /// brain_temp_approx is an estimated honey-bee thoracic/brain-equivalent temperature,
-/// reconstructed from ambient + hive internal + solar load models.[file:10]
+/// reconstructed from ambient + hive internal + solar load models (see
+/// [`BrainTempModel`]); it is `None` when not yet estimated.[file:10]
+///
+/// A reading that is missing or non-finite is unknown: time attributed to a
+/// sample with an unknown reading on an active limit is counted as unknown,
+/// never as safe.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HiveThermalSample {
     pub timestamp: OffsetDateTime,
@@ -21,7 +51,10 @@ pub struct HiveThermalSample {
     pub solar_irradiance_w_m2: f32,
     pub relative_humidity_pct: f32,
     /// Estimated neural temperature proxy for bee brain/thorax (°C).
-    pub brain_temp_approx_c: f32,
+    /// `None` when missing; fill it with [`fill_missing_brain_temp`] before
+    /// validation, or the affected time counts as unknown.
+    #[serde(default)]
+    pub brain_temp_approx_c: Option<f32>,
 }
 
 /// Safety corridor thresholds for honey-bee welfare.
@@ -40,6 +73,26 @@ pub struct HiveThermalCorridor {
     /// Optional Lyapunov-like requirement: how fast temperature must decrease
     /// once above threshold (°C per minute). If <= 0, disabled.
     pub min_cooldown_rate_c_per_min: f32,
+    /// Longest interval between consecutive samples (s) that is still attributed
+    /// to the later sample. Longer gaps count as unknown time, neither safe nor
+    /// unsafe. If 0, disabled.
+    #[serde(default)]
+    pub max_sample_gap_s: u64,
+    /// Minimum allowed hive internal temperature (°C) before brood chilling.
+    #[serde(default)]
+    pub min_hive_internal_c: Option<f32>,
+    /// Lower edge of the relative humidity band (%); below this brood desiccates.
+    #[serde(default)]
+    pub min_relative_humidity_pct: Option<f32>,
+    /// Upper edge of the relative humidity band (%); above this mould and
+    /// evaporative-cooling failure set in.
+    #[serde(default)]
+    pub max_relative_humidity_pct: Option<f32>,
+    /// Counterpart to `min_cooldown_rate_c_per_min`: how fast hive internal
+    /// temperature must rise once below `min_hive_internal_c` (°C per minute).
+    /// If <= 0, disabled.
+    #[serde(default)]
+    pub min_warmup_rate_c_per_min: f32,
 }
 
 /// Result of validating a time series of samples against the corridor.
@@ -48,12 +101,57 @@ pub struct HiveThermalValidationResult {
     pub hive_id: String,
     pub total_samples: usize,
     pub violations: Vec<HiveThermalViolation>,
-    /// Fraction of time (0–1) spent in fully safe corridor.
+    /// Fraction of covered time (0–1) spent in fully safe corridor.
     pub safe_fraction: f32,
+    /// Fraction (0–1) of the series time span backed by samples; the rest fell
+    /// into gaps longer than `max_sample_gap_s` or ended at a sample with an
+    /// unknown reading.
+    pub data_coverage_fraction: f32,
+    /// Data-quality problems found while validating; an HB score with warnings
+    /// or low coverage should not be read as a full-coverage score.
+    pub data_quality_warnings: Vec<DataQualityWarning>,
     /// Whether the series is considered BeeSafe-compliant.
     pub is_beesafe_compliant: bool,
     /// Honey-Bee neuro-safety score HB in [0,1], where 1 is ideal.
     pub hb_score: f32,
+    /// Per-dimension penalties behind `hb_score`: `hb_score = max(0, 1 - Σ penalty)`.
+    pub hb_breakdown: Vec<HbContribution>,
+    /// Time and depth beyond each corridor limit, in order of first breach.
+    pub limit_exposure: Vec<LimitExposure>,
+    /// Every contiguous unsafe run, in time order, whether or not it outlasted
+    /// the duration cap.
+    #[serde(default)]
+    pub excursions: Vec<ExcursionEpisode>,
+}
+
+/// One contiguous run of unsafe intervals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcursionEpisode {
+    /// Last safe sample before the run (the first unsafe interval starts here).
+    pub start: OffsetDateTime,
+    /// Last unsafe sample of the run.
+    pub end: OffsetDateTime,
+    pub duration_s: u64,
+    /// Most extreme reading of the run, on the limit with the largest excess.
+    pub peak_value: f32,
+    pub peak_limit: ThermalLimit,
+    pub breached_limits: Vec<ThermalLimit>,
+    /// Corridor version in force when the run opened.
+    #[serde(default)]
+    pub corridor_version: Option<String>,
+    /// Whether the run outlasted `max_violation_duration_s` (a hard fail).
+    pub exceeded_duration_cap: bool,
+}
+
+/// Accumulated exposure beyond one corridor limit over covered time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitExposure {
+    pub limit: ThermalLimit,
+    /// Seconds spent beyond the limit.
+    pub seconds: u64,
+    /// Integral of the distance beyond the limit over time (°C·min, or %RH·min
+    /// for humidity limits), i.e. degree-minutes.
+    pub unit_minutes: f32,
 }
 
 /// Details about each violation, including which neural-safety dimension failed.
@@ -64,6 +162,37 @@ pub struct HiveThermalViolation {
     pub kind: ViolationKind,
     pub value: f32,
     pub threshold: f32,
+    /// Version of the scheduled corridor that applied, if validated against a
+    /// [`CorridorSchedule`]; `None` for a single fixed corridor.
+    #[serde(default)]
+    pub corridor_version: Option<String>,
+}
+
+/// Data-quality issues detected in a sample series.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataQualityWarning {
+    /// No samples between `from` and `to`; the interval is counted as unknown.
+    SampleGap {
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+        gap_s: u64,
+    },
+    /// Sample timestamped earlier than the sample before it. It is still checked
+    /// against the instantaneous caps but contributes no time.
+    OutOfOrder {
+        timestamp: OffsetDateTime,
+        previous: OffsetDateTime,
+    },
+    /// Sample with the same timestamp as the sample before it. It is still
+    /// checked against the instantaneous caps but contributes no time.
+    DuplicateTimestamp { timestamp: OffsetDateTime },
+    /// Sample with a missing or non-finite reading on active corridor limits.
+    /// Unless another limit is breached, the interval ending at it is counted
+    /// as unknown time.
+    MissingReading {
+        timestamp: OffsetDateTime,
+        limits: Vec<ThermalLimit>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,6 +201,228 @@ pub enum ViolationKind {
     BeeBrainOverheat,
     HiveWbgtOverheat,
     CooldownTooSlow,
+    HiveInternalChill,
+    HumidityTooLow,
+    HumidityTooHigh,
+    WarmupTooSlow,
+    /// A contiguous unsafe run lasted longer than `max_violation_duration_s`.
+    ///
+    /// The enclosing violation carries the run duration (s) as `value` and the
+    /// corridor cap (s) as `threshold`; `timestamp` is the end of the run.
+    SustainedExceedance {
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        duration_s: u64,
+        /// Most extreme reading seen during the run, on the limit with the largest excess.
+        peak_value: f32,
+        peak_limit: ThermalLimit,
+        /// Every corridor limit breached at least once during the run.
+        breached_limits: Vec<ThermalLimit>,
+    },
+}
+
+/// Individual corridor limits, used to attribute sustained exceedance episodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThermalLimit {
+    HiveInternal,
+    BeeBrain,
+    HiveWbgt,
+    HiveInternalChill,
+    HumidityLow,
+    HumidityHigh,
+}
+
+impl ViolationKind {
+    /// Corridor limit behind an instantaneous violation; `None` for rate and
+    /// sustained-exceedance kinds.
+    pub fn limit(&self) -> Option<ThermalLimit> {
+        match self {
+            ViolationKind::HiveInternalOverheat => Some(ThermalLimit::HiveInternal),
+            ViolationKind::BeeBrainOverheat => Some(ThermalLimit::BeeBrain),
+            ViolationKind::HiveWbgtOverheat => Some(ThermalLimit::HiveWbgt),
+            ViolationKind::HiveInternalChill => Some(ThermalLimit::HiveInternalChill),
+            ViolationKind::HumidityTooLow => Some(ThermalLimit::HumidityLow),
+            ViolationKind::HumidityTooHigh => Some(ThermalLimit::HumidityHigh),
+            ViolationKind::CooldownTooSlow
+            | ViolationKind::WarmupTooSlow
+            | ViolationKind::SustainedExceedance { .. } => None,
+        }
+    }
+}
+
+impl ThermalLimit {
+    /// Every limit, upper caps first, in the order violations are reported.
+    pub const ALL: [ThermalLimit; 6] = [
+        ThermalLimit::HiveInternal,
+        ThermalLimit::BeeBrain,
+        ThermalLimit::HiveWbgt,
+        ThermalLimit::HumidityHigh,
+        ThermalLimit::HiveInternalChill,
+        ThermalLimit::HumidityLow,
+    ];
+
+    /// Instantaneous violation emitted when a sample breaches this limit.
+    pub fn violation_kind(self) -> ViolationKind {
+        match self {
+            ThermalLimit::HiveInternal => ViolationKind::HiveInternalOverheat,
+            ThermalLimit::BeeBrain => ViolationKind::BeeBrainOverheat,
+            ThermalLimit::HiveWbgt => ViolationKind::HiveWbgtOverheat,
+            ThermalLimit::HiveInternalChill => ViolationKind::HiveInternalChill,
+            ThermalLimit::HumidityLow => ViolationKind::HumidityTooLow,
+            ThermalLimit::HumidityHigh => ViolationKind::HumidityTooHigh,
+        }
+    }
+
+    /// True for floors that are breached from below (chill, dry air).
+    pub fn is_lower_bound(self) -> bool {
+        matches!(
+            self,
+            ThermalLimit::HiveInternalChill | ThermalLimit::HumidityLow
+        )
+    }
+
+    /// The sample reading this limit applies to; `None` if it is missing or
+    /// non-finite.
+    pub fn reading(self, sample: &HiveThermalSample) -> Option<f32> {
+        let value = match self {
+            ThermalLimit::HiveInternal | ThermalLimit::HiveInternalChill => {
+                Some(sample.hive_internal_temp_c)
+            }
+            ThermalLimit::BeeBrain => sample.brain_temp_approx_c,
+            ThermalLimit::HiveWbgt => Some(sample.wbgt_c),
+            ThermalLimit::HumidityLow | ThermalLimit::HumidityHigh => {
+                Some(sample.relative_humidity_pct)
+            }
+        };
+        value.filter(|v| v.is_finite())
+    }
+
+    /// The corridor edge for this limit, or `None` if the corridor leaves it unset.
+    pub fn threshold(self, corridor: &HiveThermalCorridor) -> Option<f32> {
+        match self {
+            ThermalLimit::HiveInternal => Some(corridor.max_hive_internal_c),
+            ThermalLimit::BeeBrain => Some(corridor.max_brain_temp_c),
+            ThermalLimit::HiveWbgt => Some(corridor.max_hive_wbgt_c),
+            ThermalLimit::HumidityHigh => corridor.max_relative_humidity_pct,
+            ThermalLimit::HiveInternalChill => corridor.min_hive_internal_c,
+            ThermalLimit::HumidityLow => corridor.min_relative_humidity_pct,
+        }
+    }
+
+    /// Whether `value` lies beyond `threshold` in this limit's direction.
+    pub fn is_breached_by(self, value: f32, threshold: f32) -> bool {
+        if self.is_lower_bound() {
+            value < threshold
+        } else {
+            value > threshold
+        }
+    }
+}
+
+/// Reading, limit and threshold for every corridor edge breached by `sample`.
+/// Upper caps are breached from above, lower bounds from below.
+fn exceeded_limits(
+    corridor: &HiveThermalCorridor,
+    sample: &HiveThermalSample,
+) -> Vec<(ThermalLimit, f32, f32)> {
+    ThermalLimit::ALL
+        .into_iter()
+        .filter_map(|limit| {
+            let value = limit.reading(sample)?;
+            limit
+                .threshold(corridor)
+                .filter(|t| limit.is_breached_by(value, *t))
+                .map(|t| (limit, value, t))
+        })
+        .collect()
+}
+
+/// Active corridor limits whose reading on `sample` is missing or non-finite.
+fn unknown_limits(corridor: &HiveThermalCorridor, sample: &HiveThermalSample) -> Vec<ThermalLimit> {
+    ThermalLimit::ALL
+        .into_iter()
+        .filter(|limit| limit.threshold(corridor).is_some() && limit.reading(sample).is_none())
+        .collect()
+}
+
+/// Contiguous run of unsafe intervals being tracked for the duration cap.
+#[derive(Debug, Clone)]
+struct UnsafeEpisode {
+    /// Duration cap and corridor version in force when the run opened.
+    max_duration_s: u64,
+    corridor_version: Option<String>,
+    start: OffsetDateTime,
+    last_unsafe: OffsetDateTime,
+    peak_value: f32,
+    peak_excess: f32,
+    peak_limit: ThermalLimit,
+    breached_limits: Vec<ThermalLimit>,
+}
+
+impl UnsafeEpisode {
+    fn open(start: OffsetDateTime, max_duration_s: u64, corridor_version: Option<String>) -> Self {
+        Self {
+            max_duration_s,
+            corridor_version,
+            start,
+            last_unsafe: start,
+            peak_value: f32::NEG_INFINITY,
+            peak_excess: f32::NEG_INFINITY,
+            peak_limit: ThermalLimit::HiveInternal,
+            breached_limits: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, timestamp: OffsetDateTime, exceeded: &[(ThermalLimit, f32, f32)]) {
+        self.last_unsafe = timestamp;
+        for &(limit, value, threshold) in exceeded {
+            let excess = (value - threshold).abs();
+            if excess > self.peak_excess {
+                self.peak_excess = excess;
+                self.peak_value = value;
+                self.peak_limit = limit;
+            }
+            if !self.breached_limits.contains(&limit) {
+                self.breached_limits.push(limit);
+            }
+        }
+    }
+
+    /// Close the run, returning its summary and a hard-fail violation if it
+    /// outlasted the cap.
+    fn close(self, hive_id: &str) -> (ExcursionEpisode, Option<HiveThermalViolation>) {
+        let max_duration_s = self.max_duration_s;
+        let duration_s = (self.last_unsafe - self.start).whole_seconds().max(0) as u64;
+        let episode = ExcursionEpisode {
+            start: self.start,
+            end: self.last_unsafe,
+            duration_s,
+            peak_value: self.peak_value,
+            peak_limit: self.peak_limit,
+            breached_limits: self.breached_limits,
+            corridor_version: self.corridor_version,
+            exceeded_duration_cap: duration_s > max_duration_s,
+        };
+        if !episode.exceeded_duration_cap {
+            return (episode, None);
+        }
+        let violation = HiveThermalViolation {
+            timestamp: episode.end,
+            hive_id: hive_id.to_string(),
+            kind: ViolationKind::SustainedExceedance {
+                start: episode.start,
+                end: episode.end,
+                duration_s,
+                peak_value: episode.peak_value,
+                peak_limit: episode.peak_limit,
+                breached_limits: episode.breached_limits.clone(),
+            },
+            value: duration_s as f32,
+            threshold: max_duration_s as f32,
+            corridor_version: episode.corridor_version.clone(),
+        };
+        (episode, Some(violation))
+    }
 }
 
 #[derive(Debug, Error)]
@@ -80,6 +431,10 @@ pub enum ValidationError {
     EmptySeries,
     #[error("mixed hive IDs in series: expected {expected}, found {found}")]
     MixedHiveIds { expected: String, found: String },
+    #[error("insufficient history for forecast: need {needed} samples, found {found}")]
+    InsufficientHistory { needed: usize, found: usize },
+    #[error("invalid corridor schedule: {0}")]
+    InvalidSchedule(#[from] ScheduleError),
 }
 
 /// Validate a time-ordered series of thermal samples for a single hive against
@@ -87,84 +442,314 @@ pub enum ValidationError {
 ///
 /// This function enforces:
 /// - Hard temperature caps for hive internal temp, bee neural proxy, and WBGT.
-/// - A maximum consecutive violation duration.
-/// - Optional Lyapunov-like cooldown requirement once thresholds are breached.
-/// - Computation of an HB score in [0,1], used as a honey-bee wellness identifier.[file:3][file:10]
+/// - Optional lower bounds: a hive internal chill floor and a relative humidity band.
+/// - A maximum consecutive violation duration: any contiguous unsafe run longer
+///   than `max_violation_duration_s` emits a `SustainedExceedance` episode and
+///   makes the series non-compliant regardless of `safe_fraction`.
+/// - Optional Lyapunov-like cooldown requirement once thresholds are breached,
+///   and the matching warm-up requirement once the hive is below its chill floor.
+/// - Data quality: gaps longer than `max_sample_gap_s` count as unknown time and
+///   lower `data_coverage_fraction`; out-of-order and duplicate timestamps are
+///   reported in `data_quality_warnings` instead of being silently clamped.
+/// - Computation of an HB score in [0,1], used as a honey-bee wellness identifier,
+///   with the default [`HbScoringModel`] and its per-dimension breakdown.[file:3][file:10]
+///
+/// This is a batch wrapper over [`HiveThermalStreamValidator`]; both produce
+/// identical results on the same data.
 pub fn validate_hive_series(
     corridor: &HiveThermalCorridor,
     samples: &[HiveThermalSample],
 ) -> Result<HiveThermalValidationResult, ValidationError> {
-    if samples.is_empty() {
-        return Err(ValidationError::EmptySeries);
+    let mut validator = HiveThermalStreamValidator::new(corridor.clone());
+    for sample in samples {
+        validator.push(sample)?;
     }
+    validator.snapshot()
+}
 
-    let hive_id = samples[0].hive_id.clone();
-    for s in samples.iter().skip(1) {
-        if s.hive_id != hive_id {
-            return Err(ValidationError::MixedHiveIds {
-                expected: hive_id.clone(),
-                found: s.hive_id.clone(),
-            });
+/// Incremental corridor validator for live hive telemetry.
+///
+/// Samples are pushed one at a time in time order; running safe/total seconds,
+/// the open unsafe episode and the previous sample (for the cooldown check) are
+/// kept as state, so edge gateways never need to re-validate the full day.
+/// [`snapshot`](Self::snapshot) can be taken at any point and matches what
+/// [`validate_hive_series`] returns for the samples pushed so far.
+#[derive(Debug, Clone)]
+pub struct HiveThermalStreamValidator {
+    corridors: CorridorSource,
+    scoring: HbScoringModel,
+    hive_id: Option<String>,
+    last_sample: Option<HiveThermalSample>,
+    total_samples: usize,
+    safe_seconds: f64,
+    total_seconds: f64,
+    unknown_seconds: f64,
+    /// Samples with at least one unknown reading on an active limit.
+    unknown_samples: usize,
+    violations: Vec<HiveThermalViolation>,
+    data_quality_warnings: Vec<DataQualityWarning>,
+    limit_exposure: Vec<LimitExposure>,
+    current_episode: Option<UnsafeEpisode>,
+    excursions: Vec<ExcursionEpisode>,
+    sustained_exceedance: bool,
+}
+
+/// Where the stream validator takes the corridor for each sample from.
+#[derive(Debug, Clone)]
+enum CorridorSource {
+    Fixed(HiveThermalCorridor),
+    Scheduled {
+        schedule: CorridorSchedule,
+        colony_state: Option<ColonyState>,
+    },
+}
+
+impl CorridorSource {
+    fn select(&self, timestamp: OffsetDateTime) -> (Option<&str>, &HiveThermalCorridor) {
+        match self {
+            CorridorSource::Fixed(corridor) => (None, corridor),
+            CorridorSource::Scheduled {
+                schedule,
+                colony_state,
+            } => {
+                let (version, corridor) = schedule.select(timestamp, *colony_state);
+                (Some(version), corridor)
+            }
+        }
+    }
+}
+
+impl HiveThermalStreamValidator {
+    pub fn new(corridor: HiveThermalCorridor) -> Self {
+        Self::from_source(CorridorSource::Fixed(corridor))
+    }
+
+    /// Validate against a season/diurnal/colony-state schedule; each sample is
+    /// judged against the corridor selected for its timestamp. Check the schedule
+    /// with [`CorridorSchedule::validate`] first; a season with an impossible
+    /// date silently matches fewer days than intended.
+    pub fn with_schedule(schedule: CorridorSchedule, colony_state: Option<ColonyState>) -> Self {
+        Self::from_source(CorridorSource::Scheduled {
+            schedule,
+            colony_state,
+        })
+    }
+
+    fn from_source(corridors: CorridorSource) -> Self {
+        Self {
+            corridors,
+            scoring: HbScoringModel::default(),
+            hive_id: None,
+            last_sample: None,
+            total_samples: 0,
+            safe_seconds: 0.0,
+            total_seconds: 0.0,
+            unknown_seconds: 0.0,
+            unknown_samples: 0,
+            violations: Vec::new(),
+            data_quality_warnings: Vec::new(),
+            limit_exposure: Vec::new(),
+            current_episode: None,
+            excursions: Vec::new(),
+            sustained_exceedance: false,
+        }
+    }
+
+    /// Score HB and compliance with `scoring` instead of the default model.
+    pub fn with_scoring(mut self, scoring: HbScoringModel) -> Self {
+        self.scoring = scoring;
+        self
+    }
+
+    /// Update the colony state used for scheduled corridor selection, e.g. when
+    /// an inspection finds the colony has stopped rearing brood. No effect for
+    /// a fixed corridor.
+    pub fn set_colony_state(&mut self, state: Option<ColonyState>) {
+        if let CorridorSource::Scheduled { colony_state, .. } = &mut self.corridors {
+            *colony_state = state;
         }
     }
 
-    let mut violations = Vec::new();
-    let mut safe_seconds: f64 = 0.0;
-    let mut total_seconds: f64 = 0.0;
+    /// Number of samples accepted so far.
+    pub fn total_samples(&self) -> usize {
+        self.total_samples
+    }
+
+    /// Feed the next sample. A sample for a different hive is rejected and
+    /// leaves the validator state untouched.
+    pub fn push(&mut self, sample: &HiveThermalSample) -> Result<(), ValidationError> {
+        let hive_id = match &self.hive_id {
+            Some(id) if *id != sample.hive_id => {
+                return Err(ValidationError::MixedHiveIds {
+                    expected: id.clone(),
+                    found: sample.hive_id.clone(),
+                });
+            }
+            Some(id) => id.clone(),
+            None => {
+                self.hive_id = Some(sample.hive_id.clone());
+                sample.hive_id.clone()
+            }
+        };
+        let (corridor_version, corridor) = self.corridors.select(sample.timestamp);
+        let corridor_version = corridor_version.map(str::to_string);
+        let exceeded = exceeded_limits(corridor, sample);
+        let unknown = unknown_limits(corridor, sample);
+        if !unknown.is_empty() {
+            self.unknown_samples += 1;
+            self.data_quality_warnings
+                .push(DataQualityWarning::MissingReading {
+                    timestamp: sample.timestamp,
+                    limits: unknown.clone(),
+                });
+        }
 
-    let mut current_violation_start: Option<OffsetDateTime> = None;
-    let mut last_sample: Option<&HiveThermalSample> = None;
-    let mut cooldown_violations = 0usize;
+        // Out-of-order and duplicate samples are still checked against the caps
+        // below, but never become the reference sample for time accounting.
+        let mut in_order = true;
+        if let Some(prev) = &self.last_sample {
+            if sample.timestamp < prev.timestamp {
+                in_order = false;
+                self.data_quality_warnings
+                    .push(DataQualityWarning::OutOfOrder {
+                        timestamp: sample.timestamp,
+                        previous: prev.timestamp,
+                    });
+            } else if sample.timestamp == prev.timestamp {
+                in_order = false;
+                self.data_quality_warnings
+                    .push(DataQualityWarning::DuplicateTimestamp {
+                        timestamp: sample.timestamp,
+                    });
+            }
+        }
 
-    for (idx, sample) in samples.iter().enumerate() {
-        if let Some(prev) = last_sample {
-            let dt = (sample.timestamp - prev.timestamp).whole_seconds().max(0) as f64;
-            total_seconds += dt;
+        let gap_s = match &self.last_sample {
+            Some(prev) if in_order => (sample.timestamp - prev.timestamp).whole_seconds() as u64,
+            _ => 0,
+        };
+        if corridor.max_sample_gap_s > 0 && gap_s > corridor.max_sample_gap_s {
+            let prev = self
+                .last_sample
+                .as_ref()
+                .expect("gap implies a previous sample");
+            self.unknown_seconds += gap_s as f64;
+            self.data_quality_warnings
+                .push(DataQualityWarning::SampleGap {
+                    from: prev.timestamp,
+                    to: sample.timestamp,
+                    gap_s,
+                });
 
-            let within_hive = sample.hive_internal_temp_c <= corridor.max_hive_internal_c;
-            let within_brain = sample.brain_temp_approx_c <= corridor.max_brain_temp_c;
-            let within_wbgt = sample.wbgt_c <= corridor.max_hive_wbgt_c;
+            // An unsafe run cannot be assumed to continue across an outage.
+            if let Some(episode) = self.current_episode.take() {
+                let (episode, violation) = episode.close(&hive_id);
+                self.excursions.push(episode);
+                if let Some(v) = violation {
+                    self.sustained_exceedance = true;
+                    self.violations.push(v);
+                }
+            }
+        } else if let Some(prev) = self.last_sample.as_ref().filter(|_| in_order) {
+            let dt = gap_s as f64;
 
-            let is_safe = within_hive && within_brain && within_wbgt;
-            if is_safe {
-                safe_seconds += dt;
+            if exceeded.is_empty() && !unknown.is_empty() {
+                // Nothing breached, but not every limit could be checked: the
+                // interval is unknown, not safe. An open unsafe run stays open.
+                self.unknown_seconds += dt;
+            } else if exceeded.is_empty() {
+                self.total_seconds += dt;
+                self.safe_seconds += dt;
 
-                // If we were in violation before, check max duration.
-                if let Some(start) = current_violation_start {
-                    let dur = (sample.timestamp - start).whole_seconds() as u64;
-                    if dur > corridor.max_violation_duration_s {
-                        // Already recorded detailed violations below.
+                // Leaving an unsafe run: hard-fail if it outlasted the cap.
+                if let Some(episode) = self.current_episode.take() {
+                    let (episode, violation) = episode.close(&hive_id);
+                    self.excursions.push(episode);
+                    if let Some(v) = violation {
+                        self.sustained_exceedance = true;
+                        self.violations.push(v);
                     }
-                    current_violation_start = None;
                 }
             } else {
-                if current_violation_start.is_none() {
-                    current_violation_start = Some(prev.timestamp);
+                self.total_seconds += dt;
+                for &(limit, value, threshold) in &exceeded {
+                    let idx = match self.limit_exposure.iter().position(|e| e.limit == limit) {
+                        Some(idx) => idx,
+                        None => {
+                            self.limit_exposure.push(LimitExposure {
+                                limit,
+                                seconds: 0,
+                                unit_minutes: 0.0,
+                            });
+                            self.limit_exposure.len() - 1
+                        }
+                    };
+                    let exposure = &mut self.limit_exposure[idx];
+                    exposure.seconds += gap_s;
+                    exposure.unit_minutes += (value - threshold).abs() * (dt / 60.0) as f32;
                 }
+
+                self.current_episode
+                    .get_or_insert_with(|| {
+                        UnsafeEpisode::open(
+                            prev.timestamp,
+                            corridor.max_violation_duration_s,
+                            corridor_version.clone(),
+                        )
+                    })
+                    .record(sample.timestamp, &exceeded);
             }
 
             // Lyapunov-like cooldown: if above threshold and corridor requires cooldown,
             // ensure that temperatures trend downward fast enough.
-            if corridor.min_cooldown_rate_c_per_min > 0.0 {
-                if prev.hive_internal_temp_c > corridor.max_hive_internal_c
-                    || prev.brain_temp_approx_c > corridor.max_brain_temp_c
-                    || prev.wbgt_c > corridor.max_hive_wbgt_c
+            if corridor.min_cooldown_rate_c_per_min > 0.0
+                && (prev.hive_internal_temp_c > corridor.max_hive_internal_c
+                    || prev
+                        .brain_temp_approx_c
+                        .is_some_and(|b| b > corridor.max_brain_temp_c)
+                    || prev.wbgt_c > corridor.max_hive_wbgt_c)
+            {
+                let dt_min = dt / 60.0;
+                if dt_min > 0.0 {
+                    let d_hive = prev.hive_internal_temp_c - sample.hive_internal_temp_c;
+                    // A missing reading gives NaN, which `max` below ignores.
+                    let d_brain = match (prev.brain_temp_approx_c, sample.brain_temp_approx_c) {
+                        (Some(a), Some(b)) => a - b,
+                        _ => f32::NAN,
+                    };
+                    let d_wbgt = prev.wbgt_c - sample.wbgt_c;
+                    let cooldown_rate = (d_hive.max(d_brain).max(d_wbgt)) / (dt_min as f32);
+
+                    if cooldown_rate < corridor.min_cooldown_rate_c_per_min {
+                        self.violations.push(HiveThermalViolation {
+                            timestamp: sample.timestamp,
+                            hive_id: hive_id.clone(),
+                            kind: ViolationKind::CooldownTooSlow,
+                            value: cooldown_rate,
+                            threshold: corridor.min_cooldown_rate_c_per_min,
+                            corridor_version: corridor_version.clone(),
+                        });
+                    }
+                }
+            }
+
+            // Symmetric warm-up requirement once the hive has chilled below its floor.
+            if let Some(min_hive) = corridor.min_hive_internal_c {
+                if corridor.min_warmup_rate_c_per_min > 0.0 && prev.hive_internal_temp_c < min_hive
                 {
                     let dt_min = dt / 60.0;
                     if dt_min > 0.0 {
-                        let d_hive = prev.hive_internal_temp_c - sample.hive_internal_temp_c;
-                        let d_brain = prev.brain_temp_approx_c - sample.brain_temp_approx_c;
-                        let d_wbgt = prev.wbgt_c - sample.wbgt_c;
-                        let cooldown_rate = (d_hive.max(d_brain).max(d_wbgt)) / (dt_min as f32);
-
-                        if cooldown_rate < corridor.min_cooldown_rate_c_per_min {
-                            cooldown_violations += 1;
-                            violations.push(HiveThermalViolation {
+                        let warmup_rate = (sample.hive_internal_temp_c - prev.hive_internal_temp_c)
+                            / (dt_min as f32);
+
+                        if warmup_rate < corridor.min_warmup_rate_c_per_min {
+                            self.violations.push(HiveThermalViolation {
                                 timestamp: sample.timestamp,
                                 hive_id: hive_id.clone(),
-                                kind: ViolationKind::CooldownTooSlow,
-                                value: cooldown_rate,
-                                threshold: corridor.min_cooldown_rate_c_per_min,
+                                kind: ViolationKind::WarmupTooSlow,
+                                value: warmup_rate,
+                                threshold: corridor.min_warmup_rate_c_per_min,
+                                corridor_version: corridor_version.clone(),
                             });
                         }
                     }
@@ -173,77 +758,87 @@ pub fn validate_hive_series(
         }
 
         // Instantaneous hard threshold checks.
-        if sample.hive_internal_temp_c > corridor.max_hive_internal_c {
-            violations.push(HiveThermalViolation {
+        for (limit, value, threshold) in exceeded {
+            self.violations.push(HiveThermalViolation {
                 timestamp: sample.timestamp,
                 hive_id: hive_id.clone(),
-                kind: ViolationKind::HiveInternalOverheat,
-                value: sample.hive_internal_temp_c,
-                threshold: corridor.max_hive_internal_c,
+                kind: limit.violation_kind(),
+                value,
+                threshold,
+                corridor_version: corridor_version.clone(),
             });
         }
-        if sample.brain_temp_approx_c > corridor.max_brain_temp_c {
-            violations.push(HiveThermalViolation {
-                timestamp: sample.timestamp,
-                hive_id: hive_id.clone(),
-                kind: ViolationKind::BeeBrainOverheat,
-                value: sample.brain_temp_approx_c,
-                threshold: corridor.max_brain_temp_c,
-            });
-        }
-        if sample.wbgt_c > corridor.max_hive_wbgt_c {
-            violations.push(HiveThermalViolation {
-                timestamp: sample.timestamp,
-                hive_id: hive_id.clone(),
-                kind: ViolationKind::HiveWbgtOverheat,
-                value: sample.wbgt_c,
-                threshold: corridor.max_hive_wbgt_c,
-            });
+
+        if in_order {
+            self.last_sample = Some(sample.clone());
         }
+        self.total_samples += 1;
+        Ok(())
+    }
 
-        last_sample = Some(sample);
+    /// Result for the samples pushed so far. A run still open at the latest
+    /// sample is judged on its duration so far, without closing it.
+    pub fn snapshot(&self) -> Result<HiveThermalValidationResult, ValidationError> {
+        let hive_id = self.hive_id.clone().ok_or(ValidationError::EmptySeries)?;
 
-        // Check ongoing violation duration at the last sample.
-        if idx == samples.len() - 1 {
-            if let Some(start) = current_violation_start {
-                let dur = (sample.timestamp - start).whole_seconds() as u64;
-                if dur > corridor.max_violation_duration_s {
-                    // We can treat this as an implicit "neural distress" window.
-                    // The detailed instantaneous violations are already collected.
-                }
+        let mut violations = self.violations.clone();
+        let mut excursions = self.excursions.clone();
+        let mut sustained_exceedance = self.sustained_exceedance;
+        if let Some(episode) = self.current_episode.clone() {
+            let (episode, violation) = episode.close(&hive_id);
+            excursions.push(episode);
+            if let Some(v) = violation {
+                sustained_exceedance = true;
+                violations.push(v);
             }
         }
-    }
 
-    if total_seconds <= 0.0 {
-        // Degenerate case: single sample or zero dt; treat as instantaneous check.
-        total_seconds = 1.0;
-        safe_seconds = if violations.is_empty() { 1.0 } else { 0.0 };
-    }
+        let span_seconds = self.total_seconds + self.unknown_seconds;
+        let data_coverage_fraction = if span_seconds > 0.0 {
+            (self.total_seconds / span_seconds) as f32
+        } else {
+            1.0
+        };
 
-    let safe_fraction = (safe_seconds / total_seconds).clamp(0.0, 1.0) as f32;
+        let mut safe_seconds = self.safe_seconds;
+        let mut total_seconds = self.total_seconds;
+        if total_seconds <= 0.0 {
+            // Degenerate case: single sample, zero dt or no known interval;
+            // treat as instantaneous check. Unknown readings are not safe.
+            total_seconds = 1.0;
+            safe_seconds = if violations.is_empty() && self.unknown_samples == 0 {
+                1.0
+            } else {
+                0.0
+            };
+        }
 
-    // HB score: penalize both time outside corridors and number of violations.
-    // Start from safe_fraction, then apply a modest penalty per violation.
-    let violation_penalty = 0.02 * (violations.len() as f32 + cooldown_violations as f32);
-    let mut hb_score = safe_fraction - violation_penalty;
-    if hb_score < 0.0 {
-        hb_score = 0.0;
-    }
+        let safe_fraction = (safe_seconds / total_seconds).clamp(0.0, 1.0) as f32;
 
-    // BeeSafe-compliant if:
-    // - At least 0.95 of time is fully safe, and
-    // - No violation pushes hb_score below 0.9.
-    let is_beesafe_compliant = safe_fraction >= 0.95 && hb_score >= 0.9;
+        let (hb_score, hb_breakdown) = self.scoring.score(&ScoringInput {
+            safe_fraction,
+            covered_seconds: self.total_seconds,
+            violations: &violations,
+            exposure: &self.limit_exposure,
+        });
+        let is_beesafe_compliant =
+            self.scoring
+                .is_compliant(safe_fraction, hb_score, sustained_exceedance);
 
-    Ok(HiveThermalValidationResult {
-        hive_id,
-        total_samples: samples.len(),
-        violations,
-        safe_fraction,
-        is_beesafe_compliant,
-        hb_score,
-    })
+        Ok(HiveThermalValidationResult {
+            hive_id,
+            total_samples: self.total_samples,
+            violations,
+            safe_fraction,
+            data_coverage_fraction,
+            data_quality_warnings: self.data_quality_warnings.clone(),
+            is_beesafe_compliant,
+            hb_score,
+            hb_breakdown,
+            limit_exposure: self.limit_exposure.clone(),
+            excursions,
+        })
+    }
 }
 
 /// A default conservative corridor for honey-bee neural safety.
@@ -252,6 +847,13 @@ pub fn validate_hive_series(
 /// - max_hive_internal_c: 35 °C (bees regulate brood area near this; we treat this as cap).
 /// - max_brain_temp_c: 39 °C (above this, neural stress risk increases).
 /// - max_hive_wbgt_c: 30 °C (local WBGT at hive; conservative vs human worker limits). [file:10]
+/// - max_sample_gap_s: 0, disabled, so every interval counts as covered time as
+///   in earlier releases. Set it (e.g. 1800 s) to count longer logger outages
+///   as unknown time and report them as [`DataQualityWarning::SampleGap`].
+///
+/// The chill floor, humidity band and warm-up rate are left unset: a broodless
+/// winter cluster legitimately runs far below brood temperature. Use
+/// [`brood_season_corridor`] while the colony is rearing brood.
 pub fn default_bee_neural_corridor() -> HiveThermalCorridor {
     HiveThermalCorridor {
         max_hive_internal_c: 35.0,
@@ -259,19 +861,35 @@ pub fn default_bee_neural_corridor() -> HiveThermalCorridor {
         max_hive_wbgt_c: 30.0,
         max_violation_duration_s: 900, // 15 minutes
         min_cooldown_rate_c_per_min: 0.5,
+        max_sample_gap_s: 0,
+        min_hive_internal_c: None,
+        min_relative_humidity_pct: None,
+        max_relative_humidity_pct: None,
+        min_warmup_rate_c_per_min: 0.0,
     }
 }
 
-// Optional: C-compatible FFI for use from C# or other languages.
-#[no_mangle]
-pub extern "C" fn hive_validator_version() -> *const u8 {
-    b"hive_thermal_corridor_validator_v0.1.0\0".as_ptr()
+/// [`default_bee_neural_corridor`] plus the lower bounds that apply while the
+/// colony is rearing brood:
+/// - min_hive_internal_c: 32 °C (brood chilling below this causes developmental defects).
+/// - relative humidity band: 30–85 % (brood desiccation below, mould and failed
+///   evaporative cooling above).
+/// - min_warmup_rate_c_per_min: 0.1 (colony must visibly re-warm once chilled).
+pub fn brood_season_corridor() -> HiveThermalCorridor {
+    HiveThermalCorridor {
+        min_hive_internal_c: Some(32.0),
+        min_relative_humidity_pct: Some(30.0),
+        max_relative_humidity_pct: Some(85.0),
+        min_warmup_rate_c_per_min: 0.1,
+        ..default_bee_neural_corridor()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
+    use time::macros::datetime;
 
     #[test]
     fn test_all_safe_series() {
@@ -288,7 +906,7 @@ mod tests {
                 wbgt_c: 28.0,
                 solar_irradiance_w_m2: 500.0,
                 relative_humidity_pct: 40.0,
-                brain_temp_approx_c: 37.0,
+                brain_temp_approx_c: Some(37.0),
             });
         }
 
@@ -315,7 +933,7 @@ mod tests {
                 wbgt_c: 31.0,
                 solar_irradiance_w_m2: 700.0,
                 relative_humidity_pct: 35.0,
-                brain_temp_approx_c: brain,
+                brain_temp_approx_c: Some(brain),
             });
         }
 
@@ -325,6 +943,287 @@ mod tests {
         assert!(result.hb_score < 0.9);
     }
 
+    /// 20 h of safe minutes, then a sparse 20-minute overheat run, then safe again.
+    fn sustained_overheat_series(hive_id: &str) -> Vec<HiveThermalSample> {
+        let base_time: OffsetDateTime = datetime!(2026-02-02 00:00:00 UTC);
+        let sample = |minute: i64, hive_internal: f32| HiveThermalSample {
+            timestamp: base_time + time::Duration::minutes(minute),
+            hive_id: hive_id.to_string(),
+            ambient_temp_c: 30.0,
+            hive_internal_temp_c: hive_internal,
+            wbgt_c: 28.0,
+            solar_irradiance_w_m2: 500.0,
+            relative_humidity_pct: 40.0,
+            brain_temp_approx_c: Some(37.0),
+        };
+
+        let mut samples: Vec<_> = (0..1200).map(|m| sample(m, 34.0)).collect();
+        samples.push(sample(1200, 36.0));
+        samples.push(sample(1220, 36.8));
+        samples.push(sample(1221, 34.0));
+        samples
+    }
+
+    #[test]
+    fn test_sustained_exceedance_is_hard_fail() {
+        let mut corridor = default_bee_neural_corridor();
+        corridor.min_cooldown_rate_c_per_min = 0.0;
+        let samples = sustained_overheat_series("hive-003");
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(result.safe_fraction >= 0.95);
+        assert!(result.hb_score >= 0.9);
+        assert!(!result.is_beesafe_compliant);
+
+        let sustained: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| matches!(v.kind, ViolationKind::SustainedExceedance { .. }))
+            .collect();
+        assert_eq!(sustained.len(), 1);
+        match &sustained[0].kind {
+            ViolationKind::SustainedExceedance {
+                start,
+                end,
+                duration_s,
+                peak_value,
+                peak_limit,
+                breached_limits,
+            } => {
+                assert_eq!(*start, samples[1199].timestamp);
+                assert_eq!(*end, samples[1201].timestamp);
+                assert_eq!(*duration_s, 21 * 60);
+                assert_abs_diff_eq!(*peak_value, 36.8, epsilon = 1e-6);
+                assert_eq!(*peak_limit, ThermalLimit::HiveInternal);
+                assert_eq!(breached_limits, &vec![ThermalLimit::HiveInternal]);
+            }
+            _ => unreachable!(),
+        }
+
+        // The same run under a longer cap is only a soft penalty.
+        corridor.max_violation_duration_s = 3600;
+        let relaxed = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(relaxed.is_beesafe_compliant);
+    }
+
+    #[test]
+    fn test_open_run_at_series_end_is_checked() {
+        let corridor = default_bee_neural_corridor();
+        let mut samples = sustained_overheat_series("hive-004");
+        samples.pop();
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(result
+            .violations
+            .iter()
+            .any(|v| matches!(v.kind, ViolationKind::SustainedExceedance { .. })));
+        assert!(!result.is_beesafe_compliant);
+    }
+
+    #[test]
+    fn test_stream_snapshot_matches_batch_at_every_prefix() {
+        let corridor = default_bee_neural_corridor();
+        let mut samples = sustained_overheat_series("hive-005");
+        samples.drain(..1150);
+
+        let mut stream = HiveThermalStreamValidator::new(corridor.clone());
+        assert!(matches!(
+            stream.snapshot(),
+            Err(ValidationError::EmptySeries)
+        ));
+        for n in 1..=samples.len() {
+            stream.push(&samples[n - 1]).unwrap();
+            let batch = validate_hive_series(&corridor, &samples[..n]).unwrap();
+            assert_eq!(
+                serde_json::to_value(stream.snapshot().unwrap()).unwrap(),
+                serde_json::to_value(batch).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_rejects_other_hive_without_state_change() {
+        let corridor = default_bee_neural_corridor();
+        let samples = sustained_overheat_series("hive-006");
+        let mut stream = HiveThermalStreamValidator::new(corridor);
+        stream.push(&samples[0]).unwrap();
+
+        let mut stray = samples[1].clone();
+        stray.hive_id = "hive-999".to_string();
+        assert!(matches!(
+            stream.push(&stray),
+            Err(ValidationError::MixedHiveIds { .. })
+        ));
+        assert_eq!(stream.total_samples(), 1);
+    }
+
+    #[test]
+    fn test_sensor_outage_counts_as_unknown_time() {
+        let mut corridor = default_bee_neural_corridor();
+        corridor.max_sample_gap_s = 1800;
+        let base_time: OffsetDateTime = datetime!(2026-02-02 18:00:00 UTC);
+        let mut samples = Vec::new();
+        for m in [0, 10, 20, 320, 330, 340] {
+            samples.push(HiveThermalSample {
+                timestamp: base_time + time::Duration::minutes(m),
+                hive_id: "hive-007".to_string(),
+                ambient_temp_c: 30.0,
+                hive_internal_temp_c: 34.0,
+                wbgt_c: 28.0,
+                solar_irradiance_w_m2: 500.0,
+                relative_humidity_pct: 40.0,
+                brain_temp_approx_c: Some(37.0),
+            });
+        }
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert_abs_diff_eq!(result.safe_fraction, 1.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(result.data_coverage_fraction, 40.0 / 340.0, epsilon = 1e-6);
+
+        // Disabled by default: the outage counts as covered, as before.
+        let legacy = validate_hive_series(&default_bee_neural_corridor(), &samples).unwrap();
+        assert_abs_diff_eq!(legacy.data_coverage_fraction, 1.0, epsilon = 1e-6);
+        assert!(legacy.data_quality_warnings.is_empty());
+        assert_eq!(
+            result.data_quality_warnings,
+            vec![DataQualityWarning::SampleGap {
+                from: samples[2].timestamp,
+                to: samples[3].timestamp,
+                gap_s: 300 * 60,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_and_duplicate_timestamps_are_reported() {
+        let corridor = default_bee_neural_corridor();
+        let mut samples = sustained_overheat_series("hive-008");
+        samples.truncate(10);
+        let duplicate = samples[4].clone();
+        let mut late = samples[2].clone();
+        late.brain_temp_approx_c = Some(40.5);
+        samples.insert(5, duplicate);
+        samples.insert(7, late);
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert_eq!(result.total_samples, 12);
+        assert_abs_diff_eq!(result.data_coverage_fraction, 1.0, epsilon = 1e-6);
+        assert_eq!(result.data_quality_warnings.len(), 2);
+        assert!(matches!(
+            result.data_quality_warnings[0],
+            DataQualityWarning::DuplicateTimestamp { .. }
+        ));
+        assert!(matches!(
+            result.data_quality_warnings[1],
+            DataQualityWarning::OutOfOrder { .. }
+        ));
+        // The late reading still counts as an instantaneous violation, but no
+        // time is attributed to it.
+        assert_eq!(result.violations.len(), 1);
+        assert_abs_diff_eq!(result.safe_fraction, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_chill_and_slow_warmup_violations() {
+        let corridor = brood_season_corridor();
+        let base_time: OffsetDateTime = datetime!(2026-02-02 05:00:00 UTC);
+        let hive_temps = [33.0, 31.0, 31.05, 31.5, 33.0];
+        let samples: Vec<_> = hive_temps
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| HiveThermalSample {
+                timestamp: base_time + time::Duration::minutes(i as i64),
+                hive_id: "hive-009".to_string(),
+                ambient_temp_c: 8.0,
+                hive_internal_temp_c: t,
+                wbgt_c: 6.0,
+                solar_irradiance_w_m2: 0.0,
+                relative_humidity_pct: 60.0,
+                brain_temp_approx_c: Some(33.0),
+            })
+            .collect();
+
+        // The default corridor has no chill floor: a cold cluster is not a violation.
+        let winter = validate_hive_series(&default_bee_neural_corridor(), &samples).unwrap();
+        assert!(winter.violations.is_empty());
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        let kinds: Vec<_> = result.violations.iter().map(|v| &v.kind).collect();
+        assert_eq!(
+            kinds
+                .iter()
+                .filter(|k| matches!(k, ViolationKind::HiveInternalChill))
+                .count(),
+            3
+        );
+        // 31.0 -> 31.05 in one minute is too slow; 31.05 -> 31.5 is fast enough.
+        let warmups: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| matches!(v.kind, ViolationKind::WarmupTooSlow))
+            .collect();
+        assert_eq!(warmups.len(), 1);
+        assert_eq!(warmups[0].timestamp, samples[2].timestamp);
+        assert!(!result.is_beesafe_compliant);
+    }
+
+    #[test]
+    fn test_humidity_band_violations() {
+        let corridor = brood_season_corridor();
+        let mut samples = sustained_overheat_series("hive-010");
+        samples.truncate(4);
+        samples[1].relative_humidity_pct = 20.0;
+        samples[3].relative_humidity_pct = 95.0;
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(matches!(
+            result.violations[0].kind,
+            ViolationKind::HumidityTooLow
+        ));
+        assert!(matches!(
+            result.violations[1].kind,
+            ViolationKind::HumidityTooHigh
+        ));
+        assert_abs_diff_eq!(result.safe_fraction, 1.0 / 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_missing_readings_count_as_unknown_time() {
+        let corridor = default_bee_neural_corridor();
+        let mut samples = sustained_overheat_series("hive-011");
+        samples.truncate(10);
+        samples[4].brain_temp_approx_c = None;
+        samples[5].hive_internal_temp_c = f32::NAN;
+
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(result.violations.is_empty());
+        assert_abs_diff_eq!(result.data_coverage_fraction, 7.0 / 9.0, epsilon = 1e-6);
+        assert_eq!(
+            result.data_quality_warnings[0],
+            DataQualityWarning::MissingReading {
+                timestamp: samples[4].timestamp,
+                limits: vec![ThermalLimit::BeeBrain],
+            }
+        );
+
+        // A missing brain temperature survives a JSON round trip.
+        let json = serde_json::to_string(&samples[4]).unwrap();
+        let back: HiveThermalSample = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.brain_temp_approx_c, None);
+
+        // A series with no usable reading is not safe.
+        for s in &mut samples {
+            s.hive_internal_temp_c = f32::NAN;
+            s.wbgt_c = f32::NAN;
+            s.brain_temp_approx_c = None;
+        }
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        assert!(!result.is_beesafe_compliant);
+        assert_eq!(result.safe_fraction, 0.0);
+        assert_eq!(result.data_coverage_fraction, 0.0);
+        assert!(result.hb_score < 1.0);
+    }
+
     #[test]
     fn test_empty_series_error() {
         let corridor = default_bee_neural_corridor();
@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::HiveThermalSample;
+
+/// Source column (CSV header or JSON key) for each sample field.
+///
+/// Defaults match the serde field names of [`HiveThermalSample`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnMapping {
+    pub timestamp: String,
+    /// `None` when the export has no hive column; see [`IngestConfig::default_hive_id`].
+    pub hive_id: Option<String>,
+    pub ambient_temp: String,
+    pub hive_internal_temp: String,
+    pub wbgt: String,
+    pub solar_irradiance: String,
+    pub relative_humidity: String,
+    /// Optional; rows without it get `None`, to be estimated with
+    /// [`fill_missing_brain_temp`](crate::fill_missing_brain_temp).
+    pub brain_temp: Option<String>,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            timestamp: "timestamp".to_string(),
+            hive_id: Some("hive_id".to_string()),
+            ambient_temp: "ambient_temp_c".to_string(),
+            hive_internal_temp: "hive_internal_temp_c".to_string(),
+            wbgt: "wbgt_c".to_string(),
+            solar_irradiance: "solar_irradiance_w_m2".to_string(),
+            relative_humidity: "relative_humidity_pct".to_string(),
+            brain_temp: Some("brain_temp_approx_c".to_string()),
+        }
+    }
+}
+
+/// Unit of every temperature column (ambient, hive, WBGT, brain).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    pub fn to_celsius(self, value: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => value,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+        }
+    }
+}
+
+/// Unit of the solar column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IrradianceUnit {
+    WattsPerSquareMetre,
+    /// Illuminance from a lux meter. Converted with the daylight luminous
+    /// efficacy of ~126.7 lm/W, so 1 lux ≈ 0.0079 W/m². This is only an
+    /// approximation (efficacy varies with sky conditions); prefer a pyranometer.
+    Lux,
+}
+
+/// Lux to W/m² for direct sunlight.
+pub const LUX_TO_W_M2: f32 = 0.0079;
+
+impl IrradianceUnit {
+    pub fn to_w_m2(self, value: f32) -> f32 {
+        match self {
+            IrradianceUnit::WattsPerSquareMetre => value,
+            IrradianceUnit::Lux => value * LUX_TO_W_M2,
+        }
+    }
+}
+
+/// How timestamp values are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimestampFormat {
+    /// `2026-02-02T18:00:00Z`, `2026-02-02T11:00:00-07:00`.
+    Rfc3339,
+    /// Seconds since the Unix epoch; fractional seconds are allowed.
+    UnixSeconds,
+    UnixMillis,
+    /// RFC 3339 if the value parses as such, otherwise Unix seconds.
+    Auto,
+}
+
+impl TimestampFormat {
+    pub fn parse(self, value: &str) -> Option<OffsetDateTime> {
+        let value = value.trim();
+        match self {
+            TimestampFormat::Rfc3339 => OffsetDateTime::parse(value, &Rfc3339).ok(),
+            TimestampFormat::UnixSeconds => parse_epoch(value, 1_000_000_000),
+            TimestampFormat::UnixMillis => parse_epoch(value, 1_000_000),
+            TimestampFormat::Auto => TimestampFormat::Rfc3339
+                .parse(value)
+                .or_else(|| TimestampFormat::UnixSeconds.parse(value)),
+        }
+    }
+}
+
+fn parse_epoch(value: &str, nanos_per_unit: i128) -> Option<OffsetDateTime> {
+    let nanos = match value.parse::<i64>() {
+        Ok(whole) => whole as i128 * nanos_per_unit,
+        Err(_) => {
+            let f = value.parse::<f64>().ok().filter(|f| f.is_finite())?;
+            (f * nanos_per_unit as f64).round() as i128
+        }
+    };
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+}
+
+/// Column mapping, units and format of one logger export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestConfig {
+    pub columns: ColumnMapping,
+    pub temperature_unit: TemperatureUnit,
+    pub irradiance_unit: IrradianceUnit,
+    pub timestamp_format: TimestampFormat,
+    /// Hive ID for rows without one (single-hive exports).
+    pub default_hive_id: Option<String>,
+    /// CSV field delimiter.
+    pub delimiter: char,
+}
+
+impl Default for IngestConfig {
+    fn default() -> Self {
+        Self {
+            columns: ColumnMapping::default(),
+            temperature_unit: TemperatureUnit::Celsius,
+            irradiance_unit: IrradianceUnit::WattsPerSquareMetre,
+            timestamp_format: TimestampFormat::Auto,
+            default_hive_id: None,
+            delimiter: ',',
+        }
+    }
+}
+
+/// Error that prevents reading a file at all.
+#[derive(Debug, Error)]
+pub enum IngestError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("missing CSV header row")]
+    MissingHeader,
+    #[error("CSV header has no column {column:?}")]
+    MissingColumn { column: String },
+    #[error("no hive ID column mapped and no default hive ID configured")]
+    NoHiveId,
+}
+
+/// Why a single row was rejected.
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum RowErrorKind {
+    #[error("expected {expected} fields, found {found}")]
+    FieldCount { expected: usize, found: usize },
+    #[error("missing value for {column:?}")]
+    MissingValue { column: String },
+    #[error("invalid number {value:?} in {column:?}")]
+    InvalidNumber { column: String, value: String },
+    #[error("invalid timestamp {value:?}")]
+    InvalidTimestamp { value: String },
+    #[error("invalid JSON: {message}")]
+    InvalidJson { message: String },
+}
+
+/// A rejected row, by 1-based line number in the source file.
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+#[error("line {line}: {kind}")]
+pub struct RowError {
+    pub line: usize,
+    pub kind: RowErrorKind,
+}
+
+/// Samples parsed from a file, plus every row that was skipped and why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestReport {
+    /// In file order; sort or validate per hive with
+    /// [`validate_apiary`](crate::validate_apiary) as needed.
+    pub samples: Vec<HiveThermalSample>,
+    pub errors: Vec<RowError>,
+}
+
+/// Read a CSV export with a header row.
+///
+/// Fields may be double-quoted (with `""` as an escaped quote) but a record
+/// must fit on one line. Blank lines are skipped; bad rows are reported in
+/// [`IngestReport::errors`] and the rest of the file is still read.
+pub fn read_csv<R: BufRead>(reader: R, config: &IngestConfig) -> Result<IngestReport, IngestError> {
+    check_hive_id_source(config)?;
+    let mut lines = reader.lines().enumerate();
+
+    let header = loop {
+        match lines.next() {
+            Some((_, line)) => {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    break split_csv_line(line.trim_start_matches('\u{feff}'), config.delimiter);
+                }
+            }
+            None => return Err(IngestError::MissingHeader),
+        }
+    };
+    let index = |column: &str| {
+        header
+            .iter()
+            .position(|h| h.trim() == column)
+            .ok_or_else(|| IngestError::MissingColumn {
+                column: column.to_string(),
+            })
+    };
+    let columns = &config.columns;
+    let optional_index = |column: &Option<String>| column.as_deref().map(index).transpose();
+    let positions = FieldPositions {
+        timestamp: index(&columns.timestamp)?,
+        hive_id: optional_index(&columns.hive_id)?,
+        ambient_temp: index(&columns.ambient_temp)?,
+        hive_internal_temp: index(&columns.hive_internal_temp)?,
+        wbgt: index(&columns.wbgt)?,
+        solar_irradiance: index(&columns.solar_irradiance)?,
+        relative_humidity: index(&columns.relative_humidity)?,
+        brain_temp: optional_index(&columns.brain_temp)?,
+    };
+
+    let mut report = IngestReport {
+        samples: Vec::new(),
+        errors: Vec::new(),
+    };
+    for (i, line) in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(&line, config.delimiter);
+        let row = if fields.len() != header.len() {
+            Err(RowErrorKind::FieldCount {
+                expected: header.len(),
+                found: fields.len(),
+            })
+        } else {
+            parse_row(config, |field| {
+                positions
+                    .get(field)
+                    .map(|p| fields[p].trim())
+                    .filter(|v| !v.is_empty())
+            })
+        };
+        push_row(&mut report, i + 1, row);
+    }
+    Ok(report)
+}
+
+/// Read JSON Lines: one object per line, keyed by the mapped column names.
+/// Values may be numbers or numeric strings. Blank lines are skipped.
+pub fn read_jsonl<R: BufRead>(
+    reader: R,
+    config: &IngestConfig,
+) -> Result<IngestReport, IngestError> {
+    check_hive_id_source(config)?;
+    let mut report = IngestReport {
+        samples: Vec::new(),
+        errors: Vec::new(),
+    };
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = serde_json::from_str::<HashMap<String, serde_json::Value>>(&line)
+            .map_err(|e| RowErrorKind::InvalidJson {
+                message: e.to_string(),
+            })
+            .and_then(|object| {
+                let values: HashMap<&str, String> = object
+                    .iter()
+                    .filter_map(|(k, v)| {
+                        let text = match v {
+                            serde_json::Value::String(s) => s.clone(),
+                            serde_json::Value::Number(n) => n.to_string(),
+                            _ => return None,
+                        };
+                        Some((k.as_str(), text))
+                    })
+                    .collect();
+                parse_row(config, |field| {
+                    config
+                        .columns
+                        .column(field)
+                        .and_then(|c| values.get(c))
+                        .map(|v| v.trim())
+                        .filter(|v| !v.is_empty())
+                })
+            });
+        push_row(&mut report, i + 1, row);
+    }
+    Ok(report)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Timestamp,
+    HiveId,
+    AmbientTemp,
+    HiveInternalTemp,
+    Wbgt,
+    SolarIrradiance,
+    RelativeHumidity,
+    BrainTemp,
+}
+
+impl ColumnMapping {
+    fn column(&self, field: Field) -> Option<&str> {
+        match field {
+            Field::Timestamp => Some(&self.timestamp),
+            Field::HiveId => self.hive_id.as_deref(),
+            Field::AmbientTemp => Some(&self.ambient_temp),
+            Field::HiveInternalTemp => Some(&self.hive_internal_temp),
+            Field::Wbgt => Some(&self.wbgt),
+            Field::SolarIrradiance => Some(&self.solar_irradiance),
+            Field::RelativeHumidity => Some(&self.relative_humidity),
+            Field::BrainTemp => self.brain_temp.as_deref(),
+        }
+    }
+}
+
+/// CSV column index of each mapped field.
+struct FieldPositions {
+    timestamp: usize,
+    hive_id: Option<usize>,
+    ambient_temp: usize,
+    hive_internal_temp: usize,
+    wbgt: usize,
+    solar_irradiance: usize,
+    relative_humidity: usize,
+    brain_temp: Option<usize>,
+}
+
+impl FieldPositions {
+    fn get(&self, field: Field) -> Option<usize> {
+        match field {
+            Field::Timestamp => Some(self.timestamp),
+            Field::HiveId => self.hive_id,
+            Field::AmbientTemp => Some(self.ambient_temp),
+            Field::HiveInternalTemp => Some(self.hive_internal_temp),
+            Field::Wbgt => Some(self.wbgt),
+            Field::SolarIrradiance => Some(self.solar_irradiance),
+            Field::RelativeHumidity => Some(self.relative_humidity),
+            Field::BrainTemp => self.brain_temp,
+        }
+    }
+}
+
+fn check_hive_id_source(config: &IngestConfig) -> Result<(), IngestError> {
+    if config.columns.hive_id.is_none() && config.default_hive_id.is_none() {
+        return Err(IngestError::NoHiveId);
+    }
+    Ok(())
+}
+
+fn push_row(report: &mut IngestReport, line: usize, row: Result<HiveThermalSample, RowErrorKind>) {
+    match row {
+        Ok(sample) => report.samples.push(sample),
+        Err(kind) => report.errors.push(RowError { line, kind }),
+    }
+}
+
+/// Build a sample from one row; `value` returns the non-empty raw text of a field.
+fn parse_row<'a>(
+    config: &IngestConfig,
+    value: impl Fn(Field) -> Option<&'a str>,
+) -> Result<HiveThermalSample, RowErrorKind> {
+    let columns = &config.columns;
+    let required = |field: Field| {
+        value(field).ok_or_else(|| RowErrorKind::MissingValue {
+            column: columns.column(field).unwrap_or_default().to_string(),
+        })
+    };
+    // `f32::from_str` also accepts "NaN" and "inf"; neither is a reading.
+    let number = |field: Field, raw: &str| {
+        raw.parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| RowErrorKind::InvalidNumber {
+                column: columns.column(field).unwrap_or_default().to_string(),
+                value: raw.to_string(),
+            })
+    };
+    let temperature = |field: Field| {
+        let raw = required(field)?;
+        number(field, raw).map(|v| config.temperature_unit.to_celsius(v))
+    };
+
+    let raw_ts = required(Field::Timestamp)?;
+    let timestamp =
+        config
+            .timestamp_format
+            .parse(raw_ts)
+            .ok_or_else(|| RowErrorKind::InvalidTimestamp {
+                value: raw_ts.to_string(),
+            })?;
+
+    let hive_id = match value(Field::HiveId) {
+        Some(id) => id.to_string(),
+        None => config
+            .default_hive_id
+            .clone()
+            .ok_or_else(|| RowErrorKind::MissingValue {
+                column: columns.hive_id.clone().unwrap_or_default(),
+            })?,
+    };
+
+    let solar_raw = required(Field::SolarIrradiance)?;
+    let humidity_raw = required(Field::RelativeHumidity)?;
+    let brain_temp_approx_c = match value(Field::BrainTemp) {
+        Some(raw) => Some(
+            config
+                .temperature_unit
+                .to_celsius(number(Field::BrainTemp, raw)?),
+        ),
+        None => None,
+    };
+
+    Ok(HiveThermalSample {
+        timestamp,
+        hive_id,
+        ambient_temp_c: temperature(Field::AmbientTemp)?,
+        hive_internal_temp_c: temperature(Field::HiveInternalTemp)?,
+        wbgt_c: temperature(Field::Wbgt)?,
+        solar_irradiance_w_m2: config
+            .irradiance_unit
+            .to_w_m2(number(Field::SolarIrradiance, solar_raw)?),
+        relative_humidity_pct: number(Field::RelativeHumidity, humidity_raw)?,
+        brain_temp_approx_c,
+    })
+}
+
+/// Split one CSV record, honouring double quotes and `""` escapes.
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use time::macros::datetime;
+
+    #[test]
+    fn csv_with_vendor_columns_and_fahrenheit() {
+        let csv = "\
+Time,Hive,Outside F,Brood F,WBGT F,Light lux,RH %
+2026-02-02T11:00:00-07:00,hive-001,86,93.2,82.4,63291,40
+
+1770055260,hive-001,86,93.2,82.4,63291,40
+";
+        let config = IngestConfig {
+            columns: ColumnMapping {
+                timestamp: "Time".to_string(),
+                hive_id: Some("Hive".to_string()),
+                ambient_temp: "Outside F".to_string(),
+                hive_internal_temp: "Brood F".to_string(),
+                wbgt: "WBGT F".to_string(),
+                solar_irradiance: "Light lux".to_string(),
+                relative_humidity: "RH %".to_string(),
+                brain_temp: None,
+            },
+            temperature_unit: TemperatureUnit::Fahrenheit,
+            irradiance_unit: IrradianceUnit::Lux,
+            ..IngestConfig::default()
+        };
+
+        let report = read_csv(csv.as_bytes(), &config).unwrap();
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert_eq!(report.samples.len(), 2);
+
+        let s = &report.samples[0];
+        assert_eq!(s.timestamp, datetime!(2026-02-02 18:00 UTC));
+        assert_eq!(report.samples[1].timestamp, datetime!(2026-02-02 18:01 UTC));
+        assert_abs_diff_eq!(s.ambient_temp_c, 30.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(s.hive_internal_temp_c, 34.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(s.wbgt_c, 28.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(s.solar_irradiance_w_m2, 500.0, epsilon = 0.1);
+        assert_eq!(s.brain_temp_approx_c, None);
+    }
+
+    #[test]
+    fn bad_rows_are_reported_by_line_and_skipped() {
+        let csv = "\
+timestamp,hive_id,ambient_temp_c,hive_internal_temp_c,wbgt_c,solar_irradiance_w_m2,relative_humidity_pct,brain_temp_approx_c
+2026-02-02T18:00:00Z,hive-001,30,34,28,500,40,37
+yesterday,hive-001,30,34,28,500,40,37
+2026-02-02T18:02:00Z,hive-001,30,hot,28,500,40,37
+2026-02-02T18:03:00Z,hive-001,30,34,28
+\"2026-02-02T18:04:00Z\",\"hive-001\",30,34,28,500,40,
+2026-02-02T18:05:00Z,hive-001,NaN,34,28,500,40,37
+2026-02-02T18:06:00Z,hive-001,30,34,28,500,40,inf
+";
+        let report = read_csv(csv.as_bytes(), &IngestConfig::default()).unwrap();
+        assert_eq!(report.samples.len(), 2);
+        assert_eq!(report.samples[1].brain_temp_approx_c, None);
+
+        let lines: Vec<_> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [3, 4, 5, 7, 8]);
+        assert_eq!(
+            report.errors[1].kind,
+            RowErrorKind::InvalidNumber {
+                column: "hive_internal_temp_c".to_string(),
+                value: "hot".to_string()
+            }
+        );
+        assert_eq!(
+            report.errors[2].to_string(),
+            "line 5: expected 8 fields, found 5"
+        );
+        assert_eq!(
+            report.errors[3].kind,
+            RowErrorKind::InvalidNumber {
+                column: "ambient_temp_c".to_string(),
+                value: "NaN".to_string()
+            }
+        );
+        assert_eq!(
+            report.errors[4].kind,
+            RowErrorKind::InvalidNumber {
+                column: "brain_temp_approx_c".to_string(),
+                value: "inf".to_string()
+            }
+        );
+
+        let missing = read_csv("timestamp,hive_id\n".as_bytes(), &IngestConfig::default());
+        assert!(
+            matches!(missing, Err(IngestError::MissingColumn { column }) if column == "ambient_temp_c")
+        );
+    }
+
+    #[test]
+    fn jsonl_with_epoch_millis_and_default_hive() {
+        let jsonl = r#"{"ts": 1770055200000, "t_amb": 30.0, "t_hive": "34.0", "wbgt": 28, "sun": 500, "rh": 40}
+not json
+{"ts": 1770055260000, "t_amb": 30.0, "t_hive": 34.5, "wbgt": 28, "sun": 500}
+"#;
+        let config = IngestConfig {
+            columns: ColumnMapping {
+                timestamp: "ts".to_string(),
+                hive_id: None,
+                ambient_temp: "t_amb".to_string(),
+                hive_internal_temp: "t_hive".to_string(),
+                wbgt: "wbgt".to_string(),
+                solar_irradiance: "sun".to_string(),
+                relative_humidity: "rh".to_string(),
+                brain_temp: None,
+            },
+            timestamp_format: TimestampFormat::UnixMillis,
+            default_hive_id: Some("hive-007".to_string()),
+            ..IngestConfig::default()
+        };
+
+        let report = read_jsonl(jsonl.as_bytes(), &config).unwrap();
+        assert_eq!(report.samples.len(), 1);
+        assert_eq!(report.samples[0].hive_id, "hive-007");
+        assert_eq!(report.samples[0].timestamp, datetime!(2026-02-02 18:00 UTC));
+
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].line, 2);
+        assert!(matches!(
+            report.errors[0].kind,
+            RowErrorKind::InvalidJson { .. }
+        ));
+        assert_eq!(
+            report.errors[1],
+            RowError {
+                line: 3,
+                kind: RowErrorKind::MissingValue {
+                    column: "rh".to_string()
+                }
+            }
+        );
+    }
+}
@@ -0,0 +1,549 @@
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::{
+    HbContribution, HbDimension, HiveThermalCorridor, HiveThermalValidationResult, ThermalLimit,
+    ViolationKind,
+};
+
+/// Version of the [`ComplianceReport`] JSON schema. Bump on any change that
+/// renames, removes or re-types a field; adding optional fields does not.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Inspector-facing compliance report for one hive, built from a validation
+/// result and the corridor it was validated against.
+///
+/// The JSON form is stable and versioned for archiving alongside the hive's
+/// BeeShard; Markdown and HTML renderings carry the same content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    pub schema_version: u32,
+    pub hive_id: String,
+    pub is_beesafe_compliant: bool,
+    pub hb_score: f32,
+    pub safe_fraction: f32,
+    pub data_coverage_fraction: f32,
+    pub total_samples: usize,
+    pub data_quality_warning_count: usize,
+    pub corridor: HiveThermalCorridor,
+    /// Scheduled corridor versions that produced violations or excursions.
+    pub corridor_versions: Vec<String>,
+    /// Time spent beyond each limit, keyed by the violation kind it raises.
+    pub time_above_limit: Vec<TimeAboveLimit>,
+    pub violation_counts: Vec<ViolationCount>,
+    pub episodes: Vec<ReportEpisode>,
+    pub hb_breakdown: Vec<HbContribution>,
+}
+
+/// Total time and depth beyond one limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeAboveLimit {
+    /// Violation kind name, e.g. `"HiveInternalOverheat"`.
+    pub kind: String,
+    pub seconds: u64,
+    /// °C·min (or %RH·min) beyond the limit; 0 for sustained exceedance.
+    pub unit_minutes: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViolationCount {
+    pub kind: String,
+    pub count: usize,
+}
+
+/// One excursion episode, with RFC 3339 timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportEpisode {
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub end: OffsetDateTime,
+    pub duration_s: u64,
+    pub peak_value: f32,
+    pub peak_limit: ThermalLimit,
+    pub breached_limits: Vec<ThermalLimit>,
+    pub corridor_version: Option<String>,
+    pub exceeded_duration_cap: bool,
+}
+
+/// Every kind, in report order.
+const KIND_NAMES: [&str; 9] = [
+    "HiveInternalOverheat",
+    "BeeBrainOverheat",
+    "HiveWbgtOverheat",
+    "HiveInternalChill",
+    "HumidityTooLow",
+    "HumidityTooHigh",
+    "CooldownTooSlow",
+    "WarmupTooSlow",
+    "SustainedExceedance",
+];
+
+/// Serde variant name of a violation kind.
+fn kind_name(kind: &ViolationKind) -> &'static str {
+    match kind {
+        ViolationKind::HiveInternalOverheat => "HiveInternalOverheat",
+        ViolationKind::BeeBrainOverheat => "BeeBrainOverheat",
+        ViolationKind::HiveWbgtOverheat => "HiveWbgtOverheat",
+        ViolationKind::CooldownTooSlow => "CooldownTooSlow",
+        ViolationKind::HiveInternalChill => "HiveInternalChill",
+        ViolationKind::HumidityTooLow => "HumidityTooLow",
+        ViolationKind::HumidityTooHigh => "HumidityTooHigh",
+        ViolationKind::WarmupTooSlow => "WarmupTooSlow",
+        ViolationKind::SustainedExceedance { .. } => "SustainedExceedance",
+    }
+}
+
+impl ComplianceReport {
+    pub fn new(result: &HiveThermalValidationResult, corridor: &HiveThermalCorridor) -> Self {
+        let mut corridor_versions: Vec<String> = Vec::new();
+        let versions = result
+            .violations
+            .iter()
+            .filter_map(|v| v.corridor_version.as_ref())
+            .chain(
+                result
+                    .excursions
+                    .iter()
+                    .filter_map(|e| e.corridor_version.as_ref()),
+            );
+        for v in versions {
+            if !corridor_versions.contains(v) {
+                corridor_versions.push(v.clone());
+            }
+        }
+
+        let mut time_above_limit: Vec<TimeAboveLimit> = KIND_NAMES
+            .iter()
+            .filter_map(|&name| {
+                let e = result
+                    .limit_exposure
+                    .iter()
+                    .find(|e| kind_name(&e.limit.violation_kind()) == name)?;
+                Some(TimeAboveLimit {
+                    kind: name.to_string(),
+                    seconds: e.seconds,
+                    unit_minutes: e.unit_minutes,
+                })
+            })
+            .collect();
+        let sustained_s: u64 = result
+            .excursions
+            .iter()
+            .filter(|e| e.exceeded_duration_cap)
+            .map(|e| e.duration_s)
+            .sum();
+        if sustained_s > 0 {
+            time_above_limit.push(TimeAboveLimit {
+                kind: "SustainedExceedance".to_string(),
+                seconds: sustained_s,
+                unit_minutes: 0.0,
+            });
+        }
+
+        let violation_counts = KIND_NAMES
+            .iter()
+            .map(|&name| ViolationCount {
+                kind: name.to_string(),
+                count: result
+                    .violations
+                    .iter()
+                    .filter(|v| kind_name(&v.kind) == name)
+                    .count(),
+            })
+            .filter(|c| c.count > 0)
+            .collect();
+
+        let episodes = result
+            .excursions
+            .iter()
+            .map(|e| ReportEpisode {
+                start: e.start,
+                end: e.end,
+                duration_s: e.duration_s,
+                peak_value: e.peak_value,
+                peak_limit: e.peak_limit,
+                breached_limits: e.breached_limits.clone(),
+                corridor_version: e.corridor_version.clone(),
+                exceeded_duration_cap: e.exceeded_duration_cap,
+            })
+            .collect();
+
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            hive_id: result.hive_id.clone(),
+            is_beesafe_compliant: result.is_beesafe_compliant,
+            hb_score: result.hb_score,
+            safe_fraction: result.safe_fraction,
+            data_coverage_fraction: result.data_coverage_fraction,
+            total_samples: result.total_samples,
+            data_quality_warning_count: result.data_quality_warnings.len(),
+            corridor: corridor.clone(),
+            corridor_versions,
+            time_above_limit,
+            violation_counts,
+            episodes,
+            hb_breakdown: result.hb_breakdown.clone(),
+        }
+    }
+
+    /// Pretty-printed JSON in the versioned report schema.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# BeeSafe thermal compliance report: {}\n",
+            escape_markdown(&self.hive_id)
+        );
+        for table in self.tables() {
+            out.push_str(&format!("\n## {}\n\n", table.title));
+            if table.rows.is_empty() {
+                out.push_str("None.\n");
+                continue;
+            }
+            out.push_str(&format!("| {} |\n", table.headers.join(" | ")));
+            out.push_str(&format!("|{}\n", "---|".repeat(table.headers.len())));
+            for row in &table.rows {
+                let cells: Vec<_> = row
+                    .iter()
+                    .map(|c| single_line(c).replace('|', "\\|"))
+                    .collect();
+                out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+        }
+        out
+    }
+
+    /// Self-contained HTML fragment (no styles or scripts).
+    pub fn to_html(&self) -> String {
+        let mut out = format!(
+            "<h1>BeeSafe thermal compliance report: {}</h1>\n",
+            escape_html(&self.hive_id)
+        );
+        for table in self.tables() {
+            out.push_str(&format!("<h2>{}</h2>\n", escape_html(table.title)));
+            if table.rows.is_empty() {
+                out.push_str("<p>None.</p>\n");
+                continue;
+            }
+            out.push_str("<table>\n<thead><tr>");
+            for h in &table.headers {
+                out.push_str(&format!("<th>{}</th>", escape_html(h)));
+            }
+            out.push_str("</tr></thead>\n<tbody>\n");
+            for row in &table.rows {
+                out.push_str("<tr>");
+                for cell in row {
+                    out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        out
+    }
+
+    /// Report content as format-neutral tables.
+    fn tables(&self) -> Vec<Table> {
+        let verdict = if self.is_beesafe_compliant {
+            "COMPLIANT"
+        } else {
+            "NOT COMPLIANT"
+        };
+        let mut summary = vec![
+            row(["Verdict", verdict]),
+            row(["HB score", &format!("{:.3}", self.hb_score)]),
+            row(["Safe fraction", &percent(self.safe_fraction)]),
+            row(["Data coverage", &percent(self.data_coverage_fraction)]),
+            row(["Samples", &self.total_samples.to_string()]),
+            row([
+                "Data-quality warnings",
+                &self.data_quality_warning_count.to_string(),
+            ]),
+        ];
+        if !self.corridor_versions.is_empty() {
+            summary.push(row([
+                "Corridor versions",
+                &self.corridor_versions.join(", "),
+            ]));
+        }
+
+        let c = &self.corridor;
+        let optional = |v: Option<f32>, unit: &str| {
+            v.map_or_else(|| "not set".to_string(), |v| format!("{v} {unit}"))
+        };
+        let enabled = |v: f32, unit: &str| {
+            if v > 0.0 {
+                format!("{v} {unit}")
+            } else {
+                "disabled".to_string()
+            }
+        };
+        let corridor = vec![
+            row([
+                "Max hive internal",
+                &format!("{} °C", c.max_hive_internal_c),
+            ]),
+            row(["Max bee brain", &format!("{} °C", c.max_brain_temp_c)]),
+            row(["Max hive WBGT", &format!("{} °C", c.max_hive_wbgt_c)]),
+            row(["Min hive internal", &optional(c.min_hive_internal_c, "°C")]),
+            row([
+                "Min relative humidity",
+                &optional(c.min_relative_humidity_pct, "%"),
+            ]),
+            row([
+                "Max relative humidity",
+                &optional(c.max_relative_humidity_pct, "%"),
+            ]),
+            row([
+                "Max violation duration",
+                &duration(c.max_violation_duration_s),
+            ]),
+            row([
+                "Min cooldown rate",
+                &enabled(c.min_cooldown_rate_c_per_min, "°C/min"),
+            ]),
+            row([
+                "Min warm-up rate",
+                &enabled(c.min_warmup_rate_c_per_min, "°C/min"),
+            ]),
+            row([
+                "Max sample gap",
+                &if c.max_sample_gap_s > 0 {
+                    duration(c.max_sample_gap_s)
+                } else {
+                    "disabled".to_string()
+                },
+            ]),
+        ];
+
+        let time_above = self
+            .time_above_limit
+            .iter()
+            .map(|t| {
+                vec![
+                    t.kind.clone(),
+                    duration(t.seconds),
+                    format!("{:.1}", t.unit_minutes),
+                ]
+            })
+            .collect();
+
+        let counts = self
+            .violation_counts
+            .iter()
+            .map(|c| vec![c.kind.clone(), c.count.to_string()])
+            .collect();
+
+        let episodes = self
+            .episodes
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let limits: Vec<_> = e.breached_limits.iter().map(|l| format!("{l:?}")).collect();
+                vec![
+                    (i + 1).to_string(),
+                    timestamp(e.start),
+                    timestamp(e.end),
+                    duration(e.duration_s),
+                    format!("{:.2} ({:?})", e.peak_value, e.peak_limit),
+                    limits.join(", "),
+                    if e.exceeded_duration_cap { "yes" } else { "no" }.to_string(),
+                ]
+            })
+            .collect();
+
+        let breakdown = self
+            .hb_breakdown
+            .iter()
+            .map(|b| vec![dimension_label(b.dimension), format!("{:.4}", b.penalty)])
+            .collect();
+
+        vec![
+            Table::new("Summary", &["Item", "Value"], summary),
+            Table::new("Corridor", &["Limit", "Value"], corridor),
+            Table::new(
+                "Time beyond limits",
+                &["Kind", "Time", "Unit-minutes"],
+                time_above,
+            ),
+            Table::new("Violations by kind", &["Kind", "Count"], counts),
+            Table::new(
+                "Excursion episodes",
+                &[
+                    "#",
+                    "Start",
+                    "End",
+                    "Duration",
+                    "Peak",
+                    "Limits breached",
+                    "Over cap",
+                ],
+                episodes,
+            ),
+            Table::new("HB breakdown", &["Dimension", "Penalty"], breakdown),
+        ]
+    }
+}
+
+struct Table {
+    title: &'static str,
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(title: &'static str, headers: &[&str], rows: Vec<Vec<String>>) -> Self {
+        Self {
+            title,
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows,
+        }
+    }
+}
+
+fn row<const N: usize>(cells: [&str; N]) -> Vec<String> {
+    cells.iter().map(|c| c.to_string()).collect()
+}
+
+fn percent(fraction: f32) -> String {
+    format!("{:.1} %", fraction * 100.0)
+}
+
+fn duration(seconds: u64) -> String {
+    let (h, m, s) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if h > 0 {
+        format!("{h}h {m:02}m {s:02}s")
+    } else {
+        format!("{m}m {s:02}s")
+    }
+}
+
+fn timestamp(t: OffsetDateTime) -> String {
+    t.format(&Rfc3339).unwrap_or_else(|_| t.to_string())
+}
+
+fn dimension_label(dimension: HbDimension) -> String {
+    match dimension {
+        HbDimension::TimeOutsideCorridor => "Time outside corridor".to_string(),
+        HbDimension::Limit(limit) => format!("{limit:?}"),
+        HbDimension::CooldownRate => "Cooldown rate".to_string(),
+        HbDimension::WarmupRate => "Warm-up rate".to_string(),
+        HbDimension::SustainedExceedance => "Sustained exceedance".to_string(),
+    }
+}
+
+/// Newlines would end a heading or table row early.
+fn single_line(s: &str) -> String {
+    s.replace(['\r', '\n'], " ")
+}
+
+/// Backslash-escape Markdown metacharacters so free text renders literally.
+fn escape_markdown(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in single_line(s).chars() {
+        if "\\`*_{}[]()<>#+-.!|~".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_bee_neural_corridor, validate_hive_series, HiveThermalSample};
+    use time::macros::datetime;
+
+    fn report() -> ComplianceReport {
+        let corridor = default_bee_neural_corridor();
+        // Three minutes above 35 °C, then recovery.
+        let temps = [34.0, 34.0, 36.0, 35.8, 35.6, 34.0, 34.0];
+        let samples: Vec<_> = temps
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| HiveThermalSample {
+                timestamp: datetime!(2026-02-02 18:00 UTC) + time::Duration::minutes(i as i64),
+                hive_id: "hive-<01>".to_string(),
+                ambient_temp_c: 30.0,
+                hive_internal_temp_c: t,
+                wbgt_c: 28.0,
+                solar_irradiance_w_m2: 500.0,
+                relative_humidity_pct: 40.0,
+                brain_temp_approx_c: Some(37.0),
+            })
+            .collect();
+        let result = validate_hive_series(&corridor, &samples).unwrap();
+        ComplianceReport::new(&result, &corridor)
+    }
+
+    #[test]
+    fn report_totals_and_episode_table() {
+        let report = report();
+        assert_eq!(report.schema_version, REPORT_SCHEMA_VERSION);
+
+        assert_eq!(report.time_above_limit.len(), 1);
+        assert_eq!(report.time_above_limit[0].kind, "HiveInternalOverheat");
+        assert_eq!(report.time_above_limit[0].seconds, 180);
+
+        let counts: Vec<_> = report
+            .violation_counts
+            .iter()
+            .map(|c| (c.kind.as_str(), c.count))
+            .collect();
+        assert_eq!(
+            counts,
+            [("HiveInternalOverheat", 3), ("CooldownTooSlow", 2)]
+        );
+
+        assert_eq!(report.episodes.len(), 1);
+        let episode = &report.episodes[0];
+        assert_eq!(episode.start, datetime!(2026-02-02 18:01 UTC));
+        assert_eq!(episode.end, datetime!(2026-02-02 18:04 UTC));
+        assert!(!episode.exceeded_duration_cap);
+
+        let md = report.to_markdown();
+        assert!(md.contains("| Verdict | NOT COMPLIANT |"));
+        assert!(md.contains(
+            "| 1 | 2026-02-02T18:01:00Z | 2026-02-02T18:04:00Z | 3m 00s | 36.00 (HiveInternal) | HiveInternal | no |"
+        ));
+    }
+
+    #[test]
+    fn json_schema_is_versioned_with_rfc3339_times() {
+        let report = report();
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["episodes"][0]["start"], "2026-02-02T18:01:00Z");
+
+        let round_trip: ComplianceReport = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip.episodes[0].end, report.episodes[0].end);
+    }
+
+    #[test]
+    fn markdown_escapes_hive_id() {
+        let mut report = report();
+        report.hive_id = "hive *1*\n# injected".to_string();
+        let md = report.to_markdown();
+        assert!(md.starts_with("# BeeSafe thermal compliance report: hive \\*1\\* \\# injected\n"));
+        assert!(!md.contains("\n# injected"));
+    }
+
+    #[test]
+    fn html_escapes_content() {
+        let html = report().to_html();
+        assert!(html.contains("<h1>BeeSafe thermal compliance report: hive-&lt;01&gt;</h1>"));
+        assert!(html.contains("<td>HiveInternalOverheat</td><td>3</td>"));
+    }
+}
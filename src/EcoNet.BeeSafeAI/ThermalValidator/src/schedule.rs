@@ -0,0 +1,352 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::{Date, OffsetDateTime, Time, UtcOffset};
+
+use crate::{
+    HiveThermalCorridor, HiveThermalSample, HiveThermalStreamValidator,
+    HiveThermalValidationResult, ValidationError,
+};
+
+/// Colony brood status, used to pick brood-rearing vs. winter-cluster corridors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColonyState {
+    Brood,
+    Broodless,
+}
+
+/// Recurring calendar window, inclusive at both ends. A window whose start is
+/// after its end wraps the new year (e.g., 1 Nov – 28 Feb). An end of 28 Feb
+/// means the end of February, so leap days are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonWindow {
+    pub start_month: u8,
+    pub start_day: u8,
+    pub end_month: u8,
+    pub end_day: u8,
+}
+
+/// A season boundary that is not a calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("invalid season date: month {month}, day {day}")]
+pub struct InvalidSeasonDate {
+    pub month: u8,
+    pub day: u8,
+}
+
+impl SeasonWindow {
+    /// Check that both boundaries are calendar dates; 29 Feb is accepted.
+    pub fn validate(&self) -> Result<(), InvalidSeasonDate> {
+        for (month, day) in [
+            (self.start_month, self.start_day),
+            (self.end_month, self.end_day),
+        ] {
+            let max_day = match month {
+                2 => 29,
+                4 | 6 | 9 | 11 => 30,
+                1..=12 => 31,
+                _ => 0,
+            };
+            if day == 0 || day > max_day {
+                return Err(InvalidSeasonDate { month, day });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        let d = (u8::from(date.month()), date.day());
+        let start = (self.start_month, self.start_day);
+        let end = match (self.end_month, self.end_day) {
+            (2, 28) => (2, 29),
+            end => end,
+        };
+        if start <= end {
+            start <= d && d <= end
+        } else {
+            d >= start || d <= end
+        }
+    }
+}
+
+/// Local time-of-day window `[start, end)`. A window whose start is after its
+/// end wraps midnight (e.g., 20:00–06:00); `start == end` covers the whole day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeOfDayWindow {
+    pub start: Time,
+    pub end: Time,
+}
+
+impl TimeOfDayWindow {
+    pub fn contains(&self, t: Time) -> bool {
+        if self.start == self.end {
+            true
+        } else if self.start < self.end {
+            self.start <= t && t < self.end
+        } else {
+            t >= self.start || t < self.end
+        }
+    }
+}
+
+/// A corridor that applies only within its season, time-of-day window and
+/// colony state. Unset conditions match everything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledCorridor {
+    /// Identifier reported on every violation judged against this corridor.
+    pub version: String,
+    pub corridor: HiveThermalCorridor,
+    #[serde(default)]
+    pub season: Option<SeasonWindow>,
+    #[serde(default)]
+    pub time_of_day: Option<TimeOfDayWindow>,
+    #[serde(default)]
+    pub colony_state: Option<ColonyState>,
+}
+
+impl ScheduledCorridor {
+    fn matches(&self, local: OffsetDateTime, colony_state: Option<ColonyState>) -> bool {
+        let season_ok = self.season.is_none_or(|s| s.contains(local.date()));
+        let time_ok = self.time_of_day.is_none_or(|w| w.contains(local.time()));
+        let state_ok = match (self.colony_state, colony_state) {
+            (None, _) => true,
+            (Some(want), Some(have)) => want == have,
+            // A state-specific corridor never applies when the state is unknown.
+            (Some(_), None) => false,
+        };
+        season_ok && time_ok && state_ok
+    }
+}
+
+/// A schedule entry that cannot be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScheduleError {
+    #[error("corridor `{version}`: {source}")]
+    InvalidSeason {
+        version: String,
+        source: InvalidSeasonDate,
+    },
+}
+
+/// Season, diurnal and colony-state aware corridor selection.
+///
+/// Entries are tried in order and the first match wins, so list the most
+/// specific corridors first. Samples matching no entry use the fallback.
+/// Season and time-of-day are evaluated in local time at `utc_offset`; sites
+/// observing daylight saving must split their schedule accordingly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorridorSchedule {
+    pub utc_offset: UtcOffset,
+    pub entries: Vec<ScheduledCorridor>,
+    pub fallback_version: String,
+    pub fallback: HiveThermalCorridor,
+}
+
+impl CorridorSchedule {
+    pub fn new(fallback_version: impl Into<String>, fallback: HiveThermalCorridor) -> Self {
+        Self {
+            utc_offset: UtcOffset::UTC,
+            entries: Vec::new(),
+            fallback_version: fallback_version.into(),
+            fallback,
+        }
+    }
+
+    pub fn with_utc_offset(mut self, utc_offset: UtcOffset) -> Self {
+        self.utc_offset = utc_offset;
+        self
+    }
+
+    pub fn with_entry(mut self, entry: ScheduledCorridor) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Check every entry's season window.
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        for entry in &self.entries {
+            if let Some(season) = entry.season {
+                season
+                    .validate()
+                    .map_err(|source| ScheduleError::InvalidSeason {
+                        version: entry.version.clone(),
+                        source,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Version and corridor that apply at `timestamp`.
+    pub fn select(
+        &self,
+        timestamp: OffsetDateTime,
+        colony_state: Option<ColonyState>,
+    ) -> (&str, &HiveThermalCorridor) {
+        let local = timestamp.to_offset(self.utc_offset);
+        self.entries
+            .iter()
+            .find(|e| e.matches(local, colony_state))
+            .map(|e| (e.version.as_str(), &e.corridor))
+            .unwrap_or((self.fallback_version.as_str(), &self.fallback))
+    }
+}
+
+/// Like [`validate_hive_series`](crate::validate_hive_series), but each sample is
+/// judged against the corridor `schedule` selects for its timestamp, and every
+/// violation records the `corridor_version` that applied. The schedule is
+/// checked with [`CorridorSchedule::validate`] first.
+pub fn validate_hive_series_scheduled(
+    schedule: &CorridorSchedule,
+    colony_state: Option<ColonyState>,
+    samples: &[HiveThermalSample],
+) -> Result<HiveThermalValidationResult, ValidationError> {
+    schedule.validate()?;
+    let mut validator = HiveThermalStreamValidator::with_schedule(schedule.clone(), colony_state);
+    for sample in samples {
+        validator.push(sample)?;
+    }
+    validator.snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_bee_neural_corridor, ViolationKind};
+    use time::macros::{date, datetime, offset, time};
+
+    fn schedule() -> CorridorSchedule {
+        let mut midday = default_bee_neural_corridor();
+        midday.max_hive_wbgt_c = 32.0;
+        let mut winter = default_bee_neural_corridor();
+        winter.min_hive_internal_c = Some(18.0);
+
+        CorridorSchedule::new("summer-night-v1", default_bee_neural_corridor())
+            .with_utc_offset(offset!(-7))
+            .with_entry(ScheduledCorridor {
+                version: "winter-cluster-v1".to_string(),
+                corridor: winter,
+                season: Some(SeasonWindow {
+                    start_month: 11,
+                    start_day: 1,
+                    end_month: 2,
+                    end_day: 28,
+                }),
+                time_of_day: None,
+                colony_state: Some(ColonyState::Broodless),
+            })
+            .with_entry(ScheduledCorridor {
+                version: "summer-midday-v1".to_string(),
+                corridor: midday,
+                season: None,
+                time_of_day: Some(TimeOfDayWindow {
+                    start: time!(10:00),
+                    end: time!(16:00),
+                }),
+                colony_state: None,
+            })
+    }
+
+    #[test]
+    fn windows_wrap_year_end_and_midnight() {
+        let season = SeasonWindow {
+            start_month: 11,
+            start_day: 1,
+            end_month: 2,
+            end_day: 28,
+        };
+        assert!(season.contains(date!(2026 - 01 - 15)));
+        assert!(season.contains(date!(2026 - 11 - 01)));
+        assert!(!season.contains(date!(2026 - 06 - 01)));
+        // An end of 28 Feb covers the leap day too.
+        assert!(season.contains(date!(2028 - 02 - 29)));
+        assert!(!season.contains(date!(2028 - 03 - 01)));
+
+        let night = TimeOfDayWindow {
+            start: time!(20:00),
+            end: time!(06:00),
+        };
+        assert!(night.contains(time!(23:30)));
+        assert!(night.contains(time!(05:59)));
+        assert!(!night.contains(time!(06:00)));
+    }
+
+    #[test]
+    fn invalid_season_dates_are_rejected() {
+        let leap = SeasonWindow {
+            start_month: 12,
+            start_day: 1,
+            end_month: 2,
+            end_day: 29,
+        };
+        assert_eq!(leap.validate(), Ok(()));
+        let bad = SeasonWindow {
+            start_month: 4,
+            start_day: 31,
+            end_month: 13,
+            end_day: 1,
+        };
+        assert_eq!(bad.validate(), Err(InvalidSeasonDate { month: 4, day: 31 }));
+
+        let mut schedule = schedule();
+        schedule.entries[0].season = Some(SeasonWindow {
+            start_month: 11,
+            start_day: 0,
+            end_month: 2,
+            end_day: 28,
+        });
+        let err = validate_hive_series_scheduled(&schedule, None, &[]).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidSchedule(ScheduleError::InvalidSeason { ref version, .. })
+                if version == "winter-cluster-v1"
+        ));
+    }
+
+    #[test]
+    fn selection_uses_local_time_and_colony_state() {
+        let schedule = schedule();
+        // 18:00 UTC is 11:00 in Phoenix.
+        let (v, _) = schedule.select(datetime!(2026-07-01 18:00 UTC), None);
+        assert_eq!(v, "summer-midday-v1");
+        let (v, _) = schedule.select(datetime!(2026-07-01 06:00 UTC), None);
+        assert_eq!(v, "summer-night-v1");
+        // Winter corridor only applies to a broodless colony.
+        let (v, _) = schedule.select(datetime!(2026-01-10 06:00 UTC), None);
+        assert_eq!(v, "summer-night-v1");
+        let (v, _) = schedule.select(
+            datetime!(2026-01-10 06:00 UTC),
+            Some(ColonyState::Broodless),
+        );
+        assert_eq!(v, "winter-cluster-v1");
+    }
+
+    #[test]
+    fn violations_report_applied_corridor_version() {
+        let schedule = schedule();
+        // WBGT of 31 °C is tolerated at midday but not at dusk.
+        let samples: Vec<_> = (0..12)
+            .map(|i| HiveThermalSample {
+                timestamp: datetime!(2026-07-01 22:30 UTC) + time::Duration::minutes(i * 10),
+                hive_id: "hive-011".to_string(),
+                ambient_temp_c: 38.0,
+                hive_internal_temp_c: 34.5,
+                wbgt_c: 31.0,
+                solar_irradiance_w_m2: 600.0,
+                relative_humidity_pct: 35.0,
+                brain_temp_approx_c: Some(38.0),
+            })
+            .collect();
+
+        let result = validate_hive_series_scheduled(&schedule, None, &samples).unwrap();
+        let wbgt: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| matches!(v.kind, ViolationKind::HiveWbgtOverheat))
+            .collect();
+        // 22:30–23:00 UTC is before 16:00 local; 23:00 onward uses the night corridor.
+        assert_eq!(wbgt.len(), 9);
+        assert!(wbgt
+            .iter()
+            .all(|v| v.corridor_version.as_deref() == Some("summer-night-v1")));
+    }
+}
@@ -0,0 +1,276 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{HiveThermalViolation, LimitExposure, ThermalLimit, ViolationKind};
+
+/// How the HB score is computed, plus the compliance thresholds applied to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HbScoringModel {
+    pub method: HbMethod,
+    /// Minimum fraction of covered time in the fully safe corridor.
+    pub min_safe_fraction: f32,
+    /// Minimum HB score.
+    pub min_hb_score: f32,
+}
+
+/// HB scoring formula.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum HbMethod {
+    /// `HB = safe_fraction - penalty_per_violation * violations`.
+    ViolationCount { penalty_per_violation: f32 },
+    /// Severity-weighted exposure integral.
+    ///
+    /// For each limit, exposure intensity is the unit-minutes beyond the limit
+    /// (°C·min, or %RH·min for humidity) per covered hour. Each limit costs
+    /// `weight * intensity / reference_intensity_per_hour`, each cooldown or
+    /// warm-up violation costs `rate_violation_penalty`, and HB is 1 minus the
+    /// total. A brief spike barely moves the score; a long, deep excursion drives it to 0.
+    ExposureIntegral {
+        /// Intensity that drives HB to 0 on its own at weight 1.
+        reference_intensity_per_hour: f32,
+        weights: LimitWeights,
+        rate_violation_penalty: f32,
+    },
+}
+
+/// Per-limit severity weights for [`HbMethod::ExposureIntegral`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitWeights {
+    pub hive_internal: f32,
+    pub bee_brain: f32,
+    pub hive_wbgt: f32,
+    pub hive_internal_chill: f32,
+    pub humidity_low: f32,
+    pub humidity_high: f32,
+}
+
+impl LimitWeights {
+    pub fn weight(&self, limit: ThermalLimit) -> f32 {
+        match limit {
+            ThermalLimit::HiveInternal => self.hive_internal,
+            ThermalLimit::BeeBrain => self.bee_brain,
+            ThermalLimit::HiveWbgt => self.hive_wbgt,
+            ThermalLimit::HiveInternalChill => self.hive_internal_chill,
+            ThermalLimit::HumidityLow => self.humidity_low,
+            ThermalLimit::HumidityHigh => self.humidity_high,
+        }
+    }
+}
+
+impl Default for LimitWeights {
+    /// Neural and brood-temperature limits weigh most; WBGT and humidity are
+    /// leading indicators rather than direct harm.
+    fn default() -> Self {
+        Self {
+            hive_internal: 1.0,
+            bee_brain: 1.5,
+            hive_wbgt: 0.5,
+            hive_internal_chill: 1.0,
+            humidity_low: 0.25,
+            humidity_high: 0.25,
+        }
+    }
+}
+
+impl Default for HbScoringModel {
+    /// The original formula: 0.02 per violation, compliant at 0.95 / 0.9.
+    fn default() -> Self {
+        Self {
+            method: HbMethod::ViolationCount {
+                penalty_per_violation: 0.02,
+            },
+            min_safe_fraction: 0.95,
+            min_hb_score: 0.9,
+        }
+    }
+}
+
+impl HbScoringModel {
+    /// Exposure-integral scoring with default weights; 1 °C above a weight-1
+    /// limit for the whole covered time zeroes the score.
+    pub fn exposure_integral() -> Self {
+        Self {
+            method: HbMethod::ExposureIntegral {
+                reference_intensity_per_hour: 60.0,
+                weights: LimitWeights::default(),
+                rate_violation_penalty: 0.02,
+            },
+            ..Self::default()
+        }
+    }
+
+    /// Score a validated series. Returns the HB score and the per-dimension
+    /// penalties it was built from: `HB = max(0, 1 - Σ penalty)`.
+    pub fn score(&self, input: &ScoringInput<'_>) -> (f32, Vec<HbContribution>) {
+        let mut contributions = Vec::new();
+        match &self.method {
+            HbMethod::ViolationCount {
+                penalty_per_violation,
+            } => {
+                contributions.push(HbContribution {
+                    dimension: HbDimension::TimeOutsideCorridor,
+                    penalty: 1.0 - input.safe_fraction,
+                });
+                for v in input.violations {
+                    add_penalty(
+                        &mut contributions,
+                        dimension_of(&v.kind),
+                        *penalty_per_violation,
+                    );
+                }
+            }
+            HbMethod::ExposureIntegral {
+                reference_intensity_per_hour,
+                weights,
+                rate_violation_penalty,
+            } => {
+                let covered_hours = input.covered_seconds / 3600.0;
+                if covered_hours > 0.0 && *reference_intensity_per_hour > 0.0 {
+                    for e in input.exposure {
+                        let intensity = e.unit_minutes as f64 / covered_hours;
+                        let penalty = weights.weight(e.limit)
+                            * (intensity as f32 / reference_intensity_per_hour);
+                        add_penalty(&mut contributions, HbDimension::Limit(e.limit), penalty);
+                    }
+                }
+                for v in input.violations {
+                    let dimension = dimension_of(&v.kind);
+                    if matches!(
+                        dimension,
+                        HbDimension::CooldownRate | HbDimension::WarmupRate
+                    ) {
+                        add_penalty(&mut contributions, dimension, *rate_violation_penalty);
+                    }
+                }
+            }
+        }
+
+        let total: f32 = contributions.iter().map(|c| c.penalty).sum();
+        ((1.0 - total).clamp(0.0, 1.0), contributions)
+    }
+
+    /// BeeSafe compliance: enough safe time, a high enough HB score, and no
+    /// sustained exceedance (which is a hard fail under every model).
+    pub fn is_compliant(
+        &self,
+        safe_fraction: f32,
+        hb_score: f32,
+        sustained_exceedance: bool,
+    ) -> bool {
+        safe_fraction >= self.min_safe_fraction
+            && hb_score >= self.min_hb_score
+            && !sustained_exceedance
+    }
+}
+
+/// Everything a scoring model may draw on.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoringInput<'a> {
+    pub safe_fraction: f32,
+    pub covered_seconds: f64,
+    pub violations: &'a [HiveThermalViolation],
+    pub exposure: &'a [LimitExposure],
+}
+
+/// What an HB penalty is attributed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HbDimension {
+    TimeOutsideCorridor,
+    Limit(ThermalLimit),
+    CooldownRate,
+    WarmupRate,
+    SustainedExceedance,
+}
+
+/// One line of the HB score breakdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HbContribution {
+    pub dimension: HbDimension,
+    /// Amount subtracted from a perfect score of 1.
+    pub penalty: f32,
+}
+
+fn dimension_of(kind: &ViolationKind) -> HbDimension {
+    match kind {
+        ViolationKind::CooldownTooSlow => HbDimension::CooldownRate,
+        ViolationKind::WarmupTooSlow => HbDimension::WarmupRate,
+        ViolationKind::SustainedExceedance { .. } => HbDimension::SustainedExceedance,
+        other => HbDimension::Limit(other.limit().expect("instantaneous kinds map to a limit")),
+    }
+}
+
+fn add_penalty(contributions: &mut Vec<HbContribution>, dimension: HbDimension, penalty: f32) {
+    match contributions.iter_mut().find(|c| c.dimension == dimension) {
+        Some(c) => c.penalty += penalty,
+        None => contributions.push(HbContribution { dimension, penalty }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_bee_neural_corridor, HiveThermalSample, HiveThermalStreamValidator};
+    use approx::assert_abs_diff_eq;
+    use time::macros::datetime;
+
+    /// Ten one-minute samples with a cooling but still hot tail.
+    fn hot_tail_samples() -> Vec<HiveThermalSample> {
+        let hive_temps = [34.0, 34.0, 34.0, 34.0, 34.0, 34.0, 36.0, 35.8, 35.6, 34.0];
+        hive_temps
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| HiveThermalSample {
+                timestamp: datetime!(2026-02-02 18:00 UTC) + time::Duration::minutes(i as i64),
+                hive_id: "hive-012".to_string(),
+                ambient_temp_c: 30.0,
+                hive_internal_temp_c: t,
+                wbgt_c: 28.0,
+                solar_irradiance_w_m2: 500.0,
+                relative_humidity_pct: 40.0,
+                brain_temp_approx_c: Some(37.0),
+            })
+            .collect()
+    }
+
+    fn validate(model: HbScoringModel) -> crate::HiveThermalValidationResult {
+        let mut stream =
+            HiveThermalStreamValidator::new(default_bee_neural_corridor()).with_scoring(model);
+        for s in &hot_tail_samples() {
+            stream.push(s).unwrap();
+        }
+        stream.snapshot().unwrap()
+    }
+
+    #[test]
+    fn violation_count_breakdown_sums_to_score_without_double_counting() {
+        let result = validate(HbScoringModel::default());
+
+        // 3 overheat samples and 2 slow cooldowns (36.0 -> 35.8 -> 35.6).
+        let total: f32 = result.hb_breakdown.iter().map(|c| c.penalty).sum();
+        assert_abs_diff_eq!(result.hb_score, 1.0 - total, epsilon = 1e-6);
+        assert_abs_diff_eq!(result.safe_fraction, 6.0 / 9.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(result.hb_score, 6.0 / 9.0 - 0.02 * 5.0, epsilon = 1e-6);
+
+        let cooldown = result
+            .hb_breakdown
+            .iter()
+            .find(|c| c.dimension == HbDimension::CooldownRate)
+            .unwrap();
+        assert_abs_diff_eq!(cooldown.penalty, 0.04, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn exposure_integral_weights_depth_and_duration() {
+        let result = validate(HbScoringModel::exposure_integral());
+
+        // 1.0 + 0.8 + 0.6 °C·min above 35 °C over 9 covered minutes.
+        let exposure = &result.limit_exposure[0];
+        assert_eq!(exposure.limit, ThermalLimit::HiveInternal);
+        assert_abs_diff_eq!(exposure.unit_minutes, 2.4, epsilon = 1e-4);
+        assert_eq!(exposure.seconds, 180);
+
+        let intensity = 2.4 / (9.0 / 60.0);
+        let expected = 1.0 - intensity / 60.0 - 2.0 * 0.02;
+        assert_abs_diff_eq!(result.hb_score, expected, epsilon = 1e-4);
+        assert!(!result.is_beesafe_compliant);
+    }
+}
@@ -19,7 +19,7 @@ fn hb_score_improves_with_better_conditions() {
             wbgt_c: 31.5,
             solar_irradiance_w_m2: 800.0,
             relative_humidity_pct: 30.0,
-            brain_temp_approx_c: 40.5,
+            brain_temp_approx_c: Some(40.5),
         });
     }
 
@@ -36,7 +36,7 @@ fn hb_score_improves_with_better_conditions() {
             wbgt_c: 27.5,
             solar_irradiance_w_m2: 400.0,
             relative_humidity_pct: 40.0,
-            brain_temp_approx_c: 37.5,
+            brain_temp_approx_c: Some(37.5),
         });
     }
 